@@ -0,0 +1,638 @@
+use std::borrow::Cow;
+use std::time::Duration;
+use super::colour::Colour;
+use super::duration::parse_duration;
+use super::error::{ParseError, Result};
+use super::snowflake::Snowflake;
+use super::{parse_id, try_parse_channel, try_parse_emoji, try_parse_role, try_parse_username};
+
+/// The tokenizer used by [`parse_quotes`].
+///
+/// [`parse_quotes`]: fn.parse_quotes.html
+pub(crate) static QUOTES_TOKENIZER: Tokenizer = Tokenizer {
+    delimiters: Cow::Borrowed(&[' ']),
+    quotes: Cow::Borrowed(&[('"', '"')]),
+    escape: Some('\\'),
+    escape_outside_quotes: false,
+    keep_quotes: false,
+};
+
+/// A configurable splitter of command arguments.
+///
+/// The default configuration is equivalent to [`parse_quotes`]: arguments are
+/// separated by spaces, content within double quotes is one argument, and
+/// backslashes escape characters only within quotes.
+///
+/// # Examples
+///
+/// Splitting arguments as a shell would, treating single, double, and smart
+/// quotes as quotes:
+///
+/// ```rust
+/// use serenity_utils::Tokenizer;
+///
+/// let tokenizer = Tokenizer::shell();
+/// let args = tokenizer.split("ban\t“some user”\n'spam bot' it\\'s");
+///
+/// assert_eq!(args, ["ban", "some user", "spam bot", "it's"]);
+/// ```
+///
+/// Building a custom tokenizer which keeps quotes in its output:
+///
+/// ```rust
+/// use serenity_utils::Tokenizer;
+///
+/// let tokenizer = Tokenizer::new()
+///     .delimiters(&[',', ' '])
+///     .quotes(&[('«', '»')])
+///     .keep_quotes(true);
+///
+/// assert_eq!(tokenizer.split("a,«b c», d"), ["a", "«b c»", "d"]);
+/// ```
+///
+/// [`parse_quotes`]: fn.parse_quotes.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tokenizer {
+    delimiters: Cow<'static, [char]>,
+    quotes: Cow<'static, [(char, char)]>,
+    escape: Option<char>,
+    escape_outside_quotes: bool,
+    keep_quotes: bool,
+}
+
+impl Tokenizer {
+    /// Creates a tokenizer with the default configuration, equivalent to
+    /// [`parse_quotes`].
+    ///
+    /// [`parse_quotes`]: fn.parse_quotes.html
+    pub fn new() -> Tokenizer { Tokenizer::default() }
+
+    /// Creates a tokenizer which splits arguments similarly to a shell.
+    ///
+    /// Arguments are separated by any of spaces, tabs, and newlines. Double
+    /// quotes, single quotes, and their smart equivalents (`“”` and `‘’`) are
+    /// all quotes, and backslashes escape characters both within and outside
+    /// of quotes.
+    pub fn shell() -> Tokenizer {
+        Tokenizer {
+            delimiters: Cow::Borrowed(&[' ', '\t', '\n', '\r']),
+            quotes: Cow::Borrowed(&[('"', '"'), ('\'', '\''), ('“', '”'), ('‘', '’')]),
+            escape: Some('\\'),
+            escape_outside_quotes: true,
+            keep_quotes: false,
+        }
+    }
+
+    /// Sets the characters which separate arguments.
+    pub fn delimiters(mut self, delimiters: &[char]) -> Self {
+        self.delimiters = Cow::Owned(delimiters.to_vec());
+
+        self
+    }
+
+    /// Sets the pairs of opening and closing characters which quote content
+    /// as one argument.
+    pub fn quotes(mut self, quotes: &[(char, char)]) -> Self {
+        self.quotes = Cow::Owned(quotes.to_vec());
+
+        self
+    }
+
+    /// Sets the character which escapes the next character, or `None` to
+    /// disable escaping.
+    pub fn escape(mut self, escape: Option<char>) -> Self {
+        self.escape = escape;
+
+        self
+    }
+
+    /// Sets whether the escape character also escapes characters outside of
+    /// quotes.
+    pub fn escape_outside_quotes(mut self, escape_outside_quotes: bool) -> Self {
+        self.escape_outside_quotes = escape_outside_quotes;
+
+        self
+    }
+
+    /// Sets whether quote characters are kept in quoted arguments.
+    pub fn keep_quotes(mut self, keep_quotes: bool) -> Self {
+        self.keep_quotes = keep_quotes;
+
+        self
+    }
+
+    /// Splits a string into arguments.
+    ///
+    /// Use [`tokens`] to avoid allocating each argument.
+    ///
+    /// [`tokens`]: #method.tokens
+    pub fn split(&self, s: &str) -> Vec<String> {
+        self.tokens(s).map(|token| token.value.into_owned()).collect()
+    }
+
+    /// Creates an iterator over the arguments of a string, along with their
+    /// positions within it.
+    ///
+    /// Arguments are borrowed from the string, unless an escaped character
+    /// requires that an argument be copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Tokenizer;
+    /// use std::borrow::Cow;
+    ///
+    /// let tokenizer = Tokenizer::new();
+    /// let mut tokens = tokenizer.tokens(r#"kick "some \"user\"" now"#);
+    ///
+    /// let token = tokens.next().unwrap();
+    /// assert_eq!(token.value, "kick");
+    /// assert_eq!(token.offset, 0);
+    /// assert!(match token.value { Cow::Borrowed(_) => true, Cow::Owned(_) => false });
+    ///
+    /// let token = tokens.next().unwrap();
+    /// assert_eq!(token.value, r#"some "user""#);
+    /// assert_eq!(token.offset, 5);
+    /// assert!(match token.value { Cow::Borrowed(_) => false, Cow::Owned(_) => true });
+    ///
+    /// assert_eq!(tokens.next().unwrap().offset, 21);
+    /// assert!(tokens.next().is_none());
+    /// ```
+    pub fn tokens<'t, 'a>(&'t self, s: &'a str) -> Tokens<'t, 'a> {
+        Tokens {
+            tokenizer: self,
+            input: s,
+            pos: 0,
+        }
+    }
+}
+
+impl Default for Tokenizer {
+    fn default() -> Tokenizer { QUOTES_TOKENIZER.clone() }
+}
+
+/// An argument split from a string by a [`Tokenizer`].
+///
+/// [`Tokenizer`]: struct.Tokenizer.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Token<'a> {
+    /// The content of the argument.
+    ///
+    /// This is borrowed from the string being split, unless an escaped
+    /// character required that it be copied.
+    pub value: Cow<'a, str>,
+    /// The byte offset of the start of the argument within the string being
+    /// split, including any opening quote.
+    pub offset: usize,
+}
+
+/// An iterator over the arguments of a string.
+///
+/// This is created via [`Tokenizer::tokens`] or [`parse_quotes_iter`].
+///
+/// [`Tokenizer::tokens`]: struct.Tokenizer.html#method.tokens
+/// [`parse_quotes_iter`]: fn.parse_quotes_iter.html
+#[derive(Clone, Debug)]
+pub struct Tokens<'t, 'a> {
+    tokenizer: &'t Tokenizer,
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'t, 'a> Iterator for Tokens<'t, 'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let tokenizer = self.tokenizer;
+        let input = self.input;
+
+        let mut offset = None;
+        let mut start = self.pos;
+        let mut owned: Option<String> = None;
+        let mut closing_quote = None;
+        let mut escaping = false;
+
+        for (i, x) in input[self.pos..].char_indices() {
+            let i = self.pos + i;
+            let end = i + x.len_utf8();
+
+            if let Some(closing) = closing_quote {
+                if Some(x) == tokenizer.escape && !escaping {
+                    escaping = true;
+                    owned.get_or_insert_with(|| input[start..i].to_owned());
+                } else if x == closing && !escaping {
+                    if tokenizer.keep_quotes {
+                        if let Some(ref mut owned) = owned {
+                            owned.push(x);
+                        }
+                    }
+
+                    let token_end = if tokenizer.keep_quotes { end } else { i };
+                    let token = finish(input, offset, start, token_end, owned.take());
+
+                    closing_quote = None;
+
+                    if token.is_some() {
+                        self.pos = end;
+
+                        return token;
+                    }
+
+                    offset = None;
+                } else {
+                    if let Some(ref mut owned) = owned {
+                        owned.push(x);
+                    }
+
+                    escaping = false;
+                }
+            } else if escaping {
+                if let Some(ref mut owned) = owned {
+                    owned.push(x);
+                }
+
+                escaping = false;
+            } else if Some(x) == tokenizer.escape && tokenizer.escape_outside_quotes {
+                if offset.is_none() {
+                    offset = Some(i);
+                    start = i;
+                }
+
+                escaping = true;
+                owned.get_or_insert_with(|| input[start..i].to_owned());
+            } else if tokenizer.delimiters.contains(&x) {
+                if let Some(token) = finish(input, offset, start, i, owned.take()) {
+                    self.pos = end;
+
+                    return Some(token);
+                }
+
+                offset = None;
+            } else if let Some(&(_, closing)) = tokenizer.quotes.iter().find(|&&(open, _)| open == x) {
+                if let Some(token) = finish(input, offset, start, i, owned.take()) {
+                    self.pos = i;
+
+                    return Some(token);
+                }
+
+                offset = Some(i);
+                start = if tokenizer.keep_quotes { i } else { end };
+                closing_quote = Some(closing);
+            } else {
+                if offset.is_none() {
+                    offset = Some(i);
+                    start = i;
+                }
+
+                if let Some(ref mut owned) = owned {
+                    owned.push(x);
+                }
+            }
+        }
+
+        self.pos = input.len();
+
+        finish(input, offset, start, input.len(), owned)
+    }
+}
+
+/// Creates a token from the content of an argument, if it is not empty.
+fn finish<'a>(input: &'a str, offset: Option<usize>, start: usize, end: usize, owned: Option<String>) -> Option<Token<'a>> {
+    let offset = offset?;
+    let value = match owned {
+        Some(owned) => Cow::Owned(owned),
+        None => Cow::Borrowed(&input[start..end]),
+    };
+
+    if value.is_empty() {
+        None
+    } else {
+        Some(Token {
+            value,
+            offset,
+        })
+    }
+}
+
+/// A cursor over the arguments of a command, parsing each into a type as it
+/// is consumed.
+///
+/// Arguments are split in the same way as [`parse_quotes`], unless another
+/// [`Tokenizer`] is given via [`with_tokenizer`].
+///
+/// # Examples
+///
+/// Parsing the arguments of a `ban` command:
+///
+/// ```rust
+/// use serenity_utils::{Args, UserArg};
+///
+/// let mut args = Args::new("<@114941315417899012> 7 spamming invite links");
+///
+/// let user = args.single::<UserArg>().unwrap();
+/// let days = args.optional::<u64>().unwrap_or(0);
+/// let reason = args.rest();
+///
+/// assert_eq!(user, UserArg(114941315417899012));
+/// assert_eq!(days, 7);
+/// assert_eq!(reason, "spamming invite links");
+/// ```
+///
+/// [`Tokenizer`]: struct.Tokenizer.html
+/// [`parse_quotes`]: fn.parse_quotes.html
+/// [`with_tokenizer`]: #method.with_tokenizer
+#[derive(Clone, Debug)]
+pub struct Args<'a> {
+    message: &'a str,
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+}
+
+impl<'a> Args<'a> {
+    /// Splits a message into arguments in the same way as [`parse_quotes`].
+    ///
+    /// [`parse_quotes`]: fn.parse_quotes.html
+    pub fn new(message: &'a str) -> Args<'a> {
+        Args::with_tokenizer(message, &QUOTES_TOKENIZER)
+    }
+
+    /// Splits a message into arguments using the given tokenizer.
+    pub fn with_tokenizer(message: &'a str, tokenizer: &Tokenizer) -> Args<'a> {
+        Args {
+            message,
+            tokens: tokenizer.tokens(message).collect(),
+            pos: 0,
+        }
+    }
+
+    /// Returns the number of arguments that have not been consumed.
+    pub fn len(&self) -> usize { self.tokens.len() - self.pos }
+
+    /// Returns whether every argument has been consumed.
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the next argument without consuming it.
+    pub fn current(&self) -> Option<&str> {
+        self.tokens.get(self.pos).map(|token| &token.value[..])
+    }
+
+    /// Returns the byte offset of the next argument within the message,
+    /// without consuming it.
+    ///
+    /// This is useful for pointing to an argument which failed to parse.
+    pub fn offset(&self) -> Option<usize> {
+        self.tokens.get(self.pos).map(|token| token.offset)
+    }
+
+    /// Parses the next argument, consuming it if it is successfully parsed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::MissingArgument`] if there are no arguments left,
+    /// or the error from parsing the argument.
+    ///
+    /// [`ParseError::MissingArgument`]: enum.ParseError.html#variant.MissingArgument
+    pub fn single<T: FromArg>(&mut self) -> Result<T> {
+        let value = match self.current() {
+            Some(arg) => T::from_arg(arg)?,
+            None => return Err(ParseError::MissingArgument.into()),
+        };
+
+        self.pos += 1;
+
+        Ok(value)
+    }
+
+    /// Parses the next argument, consuming it only if it is successfully
+    /// parsed.
+    ///
+    /// This is useful for arguments which may be omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Args;
+    ///
+    /// let mut args = Args::new("spam");
+    ///
+    /// assert_eq!(args.optional::<u64>(), None);
+    /// assert_eq!(args.single::<String>().unwrap(), "spam");
+    /// ```
+    pub fn optional<T: FromArg>(&mut self) -> Option<T> {
+        self.single().ok()
+    }
+
+    /// Parses every remaining argument, consuming them all only if they are
+    /// all successfully parsed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::MissingArgument`] if there are no arguments left,
+    /// or the first error from parsing an argument.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{Args, RoleArg};
+    ///
+    /// let mut args = Args::new("<@&136107769680887808> 81384788765712384");
+    /// let roles = args.multiple::<RoleArg>().unwrap();
+    ///
+    /// assert_eq!(roles, vec![RoleArg(136107769680887808), RoleArg(81384788765712384)]);
+    /// assert!(args.is_empty());
+    /// ```
+    ///
+    /// [`ParseError::MissingArgument`]: enum.ParseError.html#variant.MissingArgument
+    pub fn multiple<T: FromArg>(&mut self) -> Result<Vec<T>> {
+        if self.is_empty() {
+            return Err(ParseError::MissingArgument.into());
+        }
+
+        let values = self.tokens[self.pos..]
+            .iter()
+            .map(|token| T::from_arg(&token.value))
+            .collect::<Result<Vec<T>>>()?;
+
+        self.pos = self.tokens.len();
+
+        Ok(values)
+    }
+
+    /// Consumes every remaining argument, returning the remainder of the
+    /// message as it was originally written.
+    ///
+    /// An empty string is returned if there are no arguments left.
+    pub fn rest(&mut self) -> &'a str {
+        let rest = match self.offset() {
+            Some(offset) => &self.message[offset..],
+            None => "",
+        };
+
+        self.pos = self.tokens.len();
+
+        rest.trim_end()
+    }
+}
+
+/// A type which can be parsed from a command argument.
+///
+/// This is used by [`Args`] to parse arguments.
+///
+/// # Examples
+///
+/// Implementing `FromArg` for a custom type:
+///
+/// ```rust
+/// use serenity_utils::{Args, FromArg, ParseError, Result};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Action {
+///     Kick,
+///     Ban,
+/// }
+///
+/// impl FromArg for Action {
+///     fn from_arg(arg: &str) -> Result<Self> {
+///         match arg {
+///             "kick" => Ok(Action::Kick),
+///             "ban" => Ok(Action::Ban),
+///             _ => Err(ParseError::InvalidArgument.into()),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Args::new("ban").single::<Action>().unwrap(), Action::Ban);
+/// ```
+///
+/// [`Args`]: struct.Args.html
+pub trait FromArg: Sized {
+    /// Parses an argument into this type.
+    fn from_arg(arg: &str) -> Result<Self>;
+}
+
+impl FromArg for String {
+    fn from_arg(arg: &str) -> Result<Self> { Ok(arg.to_owned()) }
+}
+
+macro_rules! from_arg_numeric {
+    ($($ty:ty, $err:ident;)*) => {
+        $(
+            impl FromArg for $ty {
+                fn from_arg(arg: &str) -> Result<Self> {
+                    arg.parse().map_err(|_| ParseError::$err.into())
+                }
+            }
+        )*
+    }
+}
+
+from_arg_numeric! {
+    u8, InvalidInteger;
+    u16, InvalidInteger;
+    u32, InvalidInteger;
+    u64, InvalidInteger;
+    i8, InvalidInteger;
+    i16, InvalidInteger;
+    i32, InvalidInteger;
+    i64, InvalidInteger;
+    f32, InvalidFloat;
+    f64, InvalidFloat;
+}
+
+impl FromArg for bool {
+    /// Parses a boolean from any of `true`/`false`, `yes`/`no`, `y`/`n`,
+    /// `on`/`off`, `enable`/`disable`, and `1`/`0`, ignoring case.
+    fn from_arg(arg: &str) -> Result<Self> {
+        match &arg.to_lowercase()[..] {
+            "true" | "yes" | "y" | "on" | "enable" | "1" => Ok(true),
+            "false" | "no" | "n" | "off" | "disable" | "0" => Ok(false),
+            _ => Err(ParseError::InvalidBool.into()),
+        }
+    }
+}
+
+impl FromArg for Colour {
+    /// Parses a colour from hexadecimal, CSS `rgb()` notation, or the name of
+    /// a preset, via its `FromStr` implementation.
+    fn from_arg(arg: &str) -> Result<Self> { arg.parse() }
+}
+
+impl FromArg for Duration {
+    /// Parses a human-readable duration, such as `1h30m`, via
+    /// [`parse_duration`].
+    ///
+    /// [`parse_duration`]: fn.parse_duration.html
+    fn from_arg(arg: &str) -> Result<Self> { parse_duration(arg) }
+}
+
+impl FromArg for Snowflake {
+    fn from_arg(arg: &str) -> Result<Self> { arg.parse() }
+}
+
+/// A user, parsed from either a user mention or a raw Id.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct UserArg(pub u64);
+
+impl FromArg for UserArg {
+    fn from_arg(arg: &str) -> Result<Self> {
+        parse_mention_or_id(arg, try_parse_username).map(UserArg)
+    }
+}
+
+/// A role, parsed from either a role mention or a raw Id.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RoleArg(pub u64);
+
+impl FromArg for RoleArg {
+    fn from_arg(arg: &str) -> Result<Self> {
+        parse_mention_or_id(arg, try_parse_role).map(RoleArg)
+    }
+}
+
+/// A channel, parsed from either a channel mention or a raw Id.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ChannelArg(pub u64);
+
+impl FromArg for ChannelArg {
+    fn from_arg(arg: &str) -> Result<Self> {
+        parse_mention_or_id(arg, try_parse_channel).map(ChannelArg)
+    }
+}
+
+/// A custom emoji, parsed from an emoji usage.
+///
+/// This is an owned equivalent of [`EmojiRef`].
+///
+/// [`EmojiRef`]: struct.EmojiRef.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct EmojiArg {
+    /// Whether the emoji is animated.
+    pub animated: bool,
+    /// The name of the emoji.
+    pub name: String,
+    /// The Id of the emoji.
+    pub id: u64,
+}
+
+impl FromArg for EmojiArg {
+    fn from_arg(arg: &str) -> Result<Self> {
+        let emoji = try_parse_emoji(arg)?;
+
+        Ok(EmojiArg {
+            animated: emoji.animated,
+            name: emoji.name.to_owned(),
+            id: emoji.id,
+        })
+    }
+}
+
+/// Parses an argument as a mention if it looks like one, or a raw Id
+/// otherwise.
+fn parse_mention_or_id<F>(arg: &str, parse_mention: F) -> Result<u64>
+    where F: Fn(&str) -> Result<u64> {
+    if arg.starts_with('<') {
+        parse_mention(arg)
+    } else {
+        parse_id(arg)
+    }
+}
@@ -1,16 +1,25 @@
 // Disable this lint to avoid it wanting to change `0xABCDEF` to `0xAB_CDEF`.
 #![allow(unreadable_literal)]
 
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::str::FromStr;
+use super::css::CSS_COLOURS;
+use super::error::{Error, ParseError};
+
 macro_rules! colour {
-    ($(#[$attr:meta] $name:ident, $val:expr;)*) => {
+    ($($(#[$attr:meta])* $name:ident, $val:expr;)*) => {
         impl Colour {
             $(
-                #[$attr]
+                $(#[$attr])*
                 pub fn $name() -> Colour {
                     Colour::new($val)
                 }
             )*
         }
+
+        /// The names and values of the presets, in alphabetical order.
+        const PRESETS: &[(&str, u32)] = &[$((stringify!($name), $val),)*];
     }
 }
 
@@ -169,6 +178,596 @@ impl Colour {
     /// [`g`]: #method.g
     /// [`b`]: #method.b
     pub fn tuple(&self) -> (u8, u8, u8) { (self.r(), self.g(), self.b()) }
+
+    /// Returns a lighter Colour, increasing its HSL lightness by the given
+    /// amount between `0.0` and `1.0`.
+    ///
+    /// This matches Sass's `lighten`, where an amount of `0.2` is `20%`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x6B717F).lighten(0.2), Colour::new(0xA1A5AF));
+    /// assert_eq!(Colour::new(0x003366).lighten(0.6), Colour::new(0x99CCFF));
+    /// ```
+    pub fn lighten(&self, amount: f32) -> Colour {
+        let (h, s, l) = self.to_hsl();
+
+        Colour::from_hsl(h, s, l + f64::from(amount))
+    }
+
+    /// Returns a darker Colour, decreasing its HSL lightness by the given
+    /// amount between `0.0` and `1.0`.
+    ///
+    /// This matches Sass's `darken`, where an amount of `0.2` is `20%`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::new(0xB37399).darken(0.2), Colour::new(0x7C4465));
+    /// assert_eq!(Colour::new(0x003366).darken(0.3), Colour::new(0x000000));
+    /// ```
+    pub fn darken(&self, amount: f32) -> Colour {
+        let (h, s, l) = self.to_hsl();
+
+        Colour::from_hsl(h, s, l - f64::from(amount))
+    }
+
+    /// Returns a more saturated Colour, increasing its HSL saturation by the
+    /// given amount between `0.0` and `1.0`.
+    ///
+    /// This matches Sass's `saturate`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::new(0xCC6699).saturate(0.2), Colour::new(0xE05299));
+    /// ```
+    pub fn saturate(&self, amount: f32) -> Colour {
+        let (h, s, l) = self.to_hsl();
+
+        Colour::from_hsl(h, s + f64::from(amount), l)
+    }
+
+    /// Returns a less saturated Colour, decreasing its HSL saturation by the
+    /// given amount between `0.0` and `1.0`.
+    ///
+    /// This matches Sass's `desaturate`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x003366).desaturate(0.2), Colour::new(0x0A335C));
+    /// ```
+    pub fn desaturate(&self, amount: f32) -> Colour {
+        let (h, s, l) = self.to_hsl();
+
+        Colour::from_hsl(h, s - f64::from(amount), l)
+    }
+
+    /// Returns a grey Colour of the same HSL lightness.
+    ///
+    /// This matches Sass's `grayscale`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x6B717F).grayscale(), Colour::new(0x757575));
+    /// ```
+    pub fn grayscale(&self) -> Colour {
+        let (h, _, l) = self.to_hsl();
+
+        Colour::from_hsl(h, 0.0, l)
+    }
+
+    /// Returns the complementary Colour, rotating its hue by 180 degrees.
+    ///
+    /// This matches Sass's `complement`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x6B717F).complement(), Colour::new(0x7F796B));
+    /// ```
+    pub fn complement(&self) -> Colour {
+        let (h, s, l) = self.to_hsl();
+
+        Colour::from_hsl(h + 180.0, s, l)
+    }
+
+    /// Returns an analogous colour scheme of this Colour and its neighbours,
+    /// with hues rotated by 30 degrees either side.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// let [left, colour, right] = Colour::new(0xFF0000).analogous();
+    ///
+    /// assert_eq!(left, Colour::new(0xFF0080));
+    /// assert_eq!(colour, Colour::new(0xFF0000));
+    /// assert_eq!(right, Colour::new(0xFF8000));
+    /// ```
+    pub fn analogous(&self) -> [Colour; 3] {
+        let (h, s, l) = self.to_hsl();
+
+        [Colour::from_hsl(h - 30.0, s, l), *self, Colour::from_hsl(h + 30.0, s, l)]
+    }
+
+    /// Returns a triadic colour scheme of this Colour and two others, with
+    /// hues rotated by 120 and 240 degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// let scheme = Colour::new(0xFF0000).triadic();
+    ///
+    /// assert_eq!(scheme, [Colour::new(0xFF0000), Colour::new(0x00FF00), Colour::new(0x0000FF)]);
+    /// ```
+    pub fn triadic(&self) -> [Colour; 3] {
+        let (h, s, l) = self.to_hsl();
+
+        [*self, Colour::from_hsl(h + 120.0, s, l), Colour::from_hsl(h + 240.0, s, l)]
+    }
+
+    /// Returns the inverse Colour, subtracting each component from `255`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::new(0xB37399).invert(), Colour::new(0x4C8C66));
+    /// ```
+    pub fn invert(&self) -> Colour { Colour(!self.0 & 0xFFFFFF) }
+
+    /// Mixes this Colour with another, where a `t` of `0.0` returns this
+    /// Colour and `1.0` returns the other.
+    ///
+    /// This matches Sass's `mix`, where `a.mix(b, 0.25)` is `mix(a, b, 75%)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// let a = Colour::new(0x003366);
+    /// let b = Colour::new(0xD2E1DD);
+    ///
+    /// assert_eq!(a.mix(b, 0.5), Colour::new(0x698AA2));
+    /// assert_eq!(a.mix(b, 0.25), Colour::new(0x355F84));
+    /// ```
+    pub fn mix(&self, other: Colour, t: f32) -> Colour {
+        let t = f64::from(t).clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| round_channel(f64::from(a) * (1.0 - t) + f64::from(b) * t);
+
+        Colour::from_rgb(
+            channel(self.r(), other.r()),
+            channel(self.g(), other.g()),
+            channel(self.b(), other.b()),
+        )
+    }
+
+    /// Returns the relative luminance of this Colour, as defined by WCAG 2,
+    /// between `0.0` for black and `1.0` for white.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x000000).luminance(), 0.0);
+    /// assert_eq!(Colour::new(0xFFFFFF).luminance(), 1.0);
+    /// ```
+    pub fn luminance(&self) -> f64 {
+        let channel = |c: u8| {
+            let c = f64::from(c) / 255.0;
+
+            if c <= 0.039_28 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        };
+
+        0.2126 * channel(self.r()) + 0.7152 * channel(self.g()) + 0.0722 * channel(self.b())
+    }
+
+    /// Returns the contrast ratio between this Colour and another, as defined
+    /// by WCAG 2, between `1.0` for identical luminance and `21.0` for black
+    /// and white.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// let ratio = Colour::new(0x000000).contrast_ratio(Colour::light_theme());
+    ///
+    /// assert_eq!(ratio, 21.0);
+    /// ```
+    pub fn contrast_ratio(&self, other: Colour) -> f64 {
+        let a = self.luminance();
+        let b = other.luminance();
+
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Returns whether text of this Colour is readable on a background
+    /// Colour, meaning that their contrast ratio is at least the `4.5` that
+    /// WCAG 2 level AA requires for normal text.
+    ///
+    /// The backgrounds of the official client's themes are available from
+    /// [`dark_theme`] and [`light_theme`].
+    ///
+    /// # Examples
+    ///
+    /// Check whether a role colour is readable on each theme:
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// let colour = Colour::gold();
+    ///
+    /// assert!(colour.is_readable_on(Colour::dark_theme()));
+    /// assert!(!colour.is_readable_on(Colour::light_theme()));
+    /// ```
+    ///
+    /// [`dark_theme`]: #method.dark_theme
+    /// [`light_theme`]: #method.light_theme
+    pub fn is_readable_on(&self, background: Colour) -> bool {
+        self.contrast_ratio(background) >= 4.5
+    }
+
+    /// Creates a new `Colour`, setting its RGB value to `(49, 51, 56)`, the
+    /// background of the official client's dark theme since its 2023 redesign.
+    ///
+    /// This is not a role colour preset, so it is not one of [`presets`].
+    ///
+    /// [`presets`]: #method.presets
+    pub fn dark_theme() -> Colour { Colour(0x313338) }
+
+    /// Creates a new `Colour`, setting its RGB value to `(255, 255, 255)`, the
+    /// background of the official client's light theme.
+    ///
+    /// This is not a role colour preset, so it is not one of [`presets`].
+    ///
+    /// [`presets`]: #method.presets
+    pub fn light_theme() -> Colour { Colour(0xFFFFFF) }
+
+    /// Returns an iterator over the names and values of every preset, such as
+    /// [`blurple`], in alphabetical order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// let (name, colour) = Colour::presets().next().unwrap();
+    ///
+    /// assert_eq!(name, "blitz_blue");
+    /// assert_eq!(colour, Colour::blitz_blue());
+    /// ```
+    ///
+    /// [`blurple`]: #method.blurple
+    pub fn presets() -> Presets {
+        Presets {
+            inner: PRESETS.iter(),
+        }
+    }
+
+    /// Looks up a Colour by the name of a preset or a CSS named colour,
+    /// ignoring case, spaces, hyphens and underscores.
+    ///
+    /// Presets take priority over CSS named colours, so `red` is the
+    /// [`red`] preset rather than CSS's `#FF0000`, and both `dark grey` and
+    /// `darkgrey` are the [`dark_grey`] preset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::by_name("Dark Teal"), Some(Colour::dark_teal()));
+    /// assert_eq!(Colour::by_name("rebecca-purple"), Some(Colour::new(0x663399)));
+    /// assert_eq!(Colour::by_name("red"), Some(Colour::red()));
+    /// assert_eq!(Colour::by_name("not a colour"), None);
+    /// ```
+    ///
+    /// [`dark_grey`]: #method.dark_grey
+    /// [`red`]: #method.red
+    pub fn by_name(name: &str) -> Option<Colour> {
+        let name = name.to_lowercase().replace([' ', '-', '_'], "");
+
+        PRESETS
+            .iter()
+            .find(|&&(preset, _)| preset.bytes().filter(|&b| b != b'_').eq(name.bytes()))
+            .or_else(|| CSS_COLOURS.iter().find(|&&(css, _)| css == name))
+            .map(|&(_, value)| Colour(value))
+    }
+
+    /// Returns the name and value of the preset which looks closest to this
+    /// Colour.
+    ///
+    /// Closeness is measured as distance in the OKLab colour space, which
+    /// matches how different colours appear far better than distance between
+    /// RGB values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// let (name, colour) = Colour::new(0x7088DD).nearest_preset();
+    ///
+    /// assert_eq!(name, "blurple");
+    /// assert_eq!(colour, Colour::blurple());
+    /// ```
+    pub fn nearest_preset(&self) -> (&'static str, Colour) {
+        nearest(*self, PRESETS)
+    }
+
+    /// Returns the name and value of the CSS named colour which looks closest
+    /// to this Colour.
+    ///
+    /// Closeness is measured in the same way as for [`nearest_preset`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// let (name, colour) = Colour::blurple().nearest_css_name();
+    ///
+    /// assert_eq!(name, "cornflowerblue");
+    /// assert_eq!(colour, Colour::new(0x6495ED));
+    /// ```
+    ///
+    /// [`nearest_preset`]: #method.nearest_preset
+    pub fn nearest_css_name(&self) -> (&'static str, Colour) {
+        nearest(*self, CSS_COLOURS)
+    }
+
+    /// Returns the hue in degrees, and the saturation and lightness between
+    /// `0.0` and `1.0`, of this Colour in the HSL colour space.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::new(0xFF0000).to_hsl(), (0.0, 1.0, 0.5));
+    /// assert_eq!(Colour::new(0x808080).to_hsl().1, 0.0);
+    /// ```
+    pub fn to_hsl(&self) -> (f64, f64, f64) {
+        let (r, g, b) = self.unit_rgb();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        let l = (max + min) / 2.0;
+
+        if delta == 0.0 {
+            return (0.0, 0.0, l);
+        }
+
+        let s = if l < 0.5 { delta / (max + min) } else { delta / (2.0 - max - min) };
+
+        (hue(r, g, b, max, delta), s, l)
+    }
+
+    /// Creates a Colour from a hue in degrees, and a saturation and lightness
+    /// which are clamped between `0.0` and `1.0`, in the HSL colour space.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_hsl(120.0, 1.0, 0.5), Colour::new(0x00FF00));
+    /// assert_eq!(Colour::from_hsl(-120.0, 1.0, 0.25), Colour::new(0x000080));
+    /// ```
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Colour {
+        let h = h.rem_euclid(360.0) / 360.0;
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        let m2 = if l <= 0.5 { l * (s + 1.0) } else { l + s - l * s };
+        let m1 = l * 2.0 - m2;
+
+        Colour::from_rgb(
+            round_channel(hue_to_rgb(m1, m2, h + 1.0 / 3.0) * 255.0),
+            round_channel(hue_to_rgb(m1, m2, h) * 255.0),
+            round_channel(hue_to_rgb(m1, m2, h - 1.0 / 3.0) * 255.0),
+        )
+    }
+
+    /// Returns the hue in degrees, and the saturation and value between `0.0`
+    /// and `1.0`, of this Colour in the HSV colour space.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x0000FF).to_hsv(), (240.0, 1.0, 1.0));
+    /// assert_eq!(Colour::new(0x000000).to_hsv(), (0.0, 0.0, 0.0));
+    /// ```
+    pub fn to_hsv(&self) -> (f64, f64, f64) {
+        let (r, g, b) = self.unit_rgb();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        if delta == 0.0 {
+            return (0.0, 0.0, max);
+        }
+
+        (hue(r, g, b, max, delta), delta / max, max)
+    }
+
+    /// Creates a Colour from a hue in degrees, and a saturation and value
+    /// which are clamped between `0.0` and `1.0`, in the HSV colour space.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_hsv(240.0, 1.0, 1.0), Colour::new(0x0000FF));
+    /// assert_eq!(Colour::from_hsv(60.0, 0.5, 0.8), Colour::new(0xCCCC66));
+    /// ```
+    pub fn from_hsv(h: f64, s: f64, v: f64) -> Colour {
+        let h = h.rem_euclid(360.0) / 60.0;
+        let s = s.clamp(0.0, 1.0);
+        let v = v.clamp(0.0, 1.0);
+        let c = v * s;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+        let m = v - c;
+
+        let (r, g, b) = match h as u8 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        Colour::from_rgb(
+            round_channel((r + m) * 255.0),
+            round_channel((g + m) * 255.0),
+            round_channel((b + m) * 255.0),
+        )
+    }
+
+    /// Returns the lightness between `0.0` and `100.0`, and the green-red and
+    /// blue-yellow axes, of this Colour in the CIELAB colour space, relative
+    /// to a D65 white point.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// let (l, a, b) = Colour::new(0xFF0000).to_lab();
+    ///
+    /// assert_eq!((l.round(), a.round(), b.round()), (53.0, 80.0, 67.0));
+    /// ```
+    pub fn to_lab(&self) -> (f64, f64, f64) {
+        let r = srgb_to_linear(self.r());
+        let g = srgb_to_linear(self.g());
+        let b = srgb_to_linear(self.b());
+
+        let x = (0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b) / D65.0;
+        let y = (0.212_672_9 * r + 0.715_152_2 * g + 0.072_175_0 * b) / D65.1;
+        let z = (0.019_333_9 * r + 0.119_192_0 * g + 0.950_304_1 * b) / D65.2;
+
+        let (fx, fy, fz) = (lab_f(x), lab_f(y), lab_f(z));
+
+        (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+    }
+
+    /// Creates a Colour from its lightness between `0.0` and `100.0`, and
+    /// green-red and blue-yellow axes, in the CIELAB colour space relative to
+    /// a D65 white point, clamping it to the sRGB gamut.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_lab(100.0, 0.0, 0.0), Colour::new(0xFFFFFF));
+    /// assert_eq!(Colour::from_lab(53.24, 80.09, 67.2), Colour::new(0xFF0000));
+    /// ```
+    pub fn from_lab(l: f64, a: f64, b: f64) -> Colour {
+        let fy = (l + 16.0) / 116.0;
+        let x = lab_f_inv(fy + a / 500.0) * D65.0;
+        let y = lab_f_inv(fy) * D65.1;
+        let z = lab_f_inv(fy - b / 200.0) * D65.2;
+
+        Colour::from_rgb(
+            linear_to_srgb(3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z),
+            linear_to_srgb(-0.969_266_0 * x + 1.876_010_8 * y + 0.041_556_0 * z),
+            linear_to_srgb(0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z),
+        )
+    }
+
+    /// Returns the lightness between `0.0` and `1.0`, and the green-red and
+    /// blue-yellow axes, of this Colour in the OKLab colour space.
+    ///
+    /// OKLab is more perceptually uniform than CIELAB, and is what
+    /// [`gradient`] and [`nearest_preset`] use to compare colours.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// let (l, a, b) = Colour::new(0xFFFFFF).to_oklab();
+    ///
+    /// assert!((l - 1.0).abs() < 1e-6 && a.abs() < 1e-6 && b.abs() < 1e-6);
+    /// ```
+    ///
+    /// [`gradient`]: fn.gradient.html
+    /// [`nearest_preset`]: #method.nearest_preset
+    pub fn to_oklab(&self) -> (f64, f64, f64) {
+        let r = srgb_to_linear(self.r());
+        let g = srgb_to_linear(self.g());
+        let b = srgb_to_linear(self.b());
+
+        let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+        let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+        let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+
+        (
+            0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s,
+            1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s,
+            0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s,
+        )
+    }
+
+    /// Creates a Colour from its lightness between `0.0` and `1.0`, and
+    /// green-red and blue-yellow axes, in the OKLab colour space, clamping it
+    /// to the sRGB gamut.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_oklab(1.0, 0.0, 0.0), Colour::new(0xFFFFFF));
+    /// assert_eq!(Colour::from_oklab(0.0, 0.0, 0.0), Colour::new(0x000000));
+    /// ```
+    pub fn from_oklab(l: f64, a: f64, b: f64) -> Colour {
+        let l_ = (l + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+        let m_ = (l - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+        let s_ = (l - 0.089_484_177_5 * a - 1.291_485_548_0 * b).powi(3);
+
+        Colour::from_rgb(
+            linear_to_srgb(4.076_741_662_1 * l_ - 3.307_711_591_3 * m_ + 0.230_969_929_2 * s_),
+            linear_to_srgb(-1.268_438_004_6 * l_ + 2.609_757_401_1 * m_ - 0.341_319_396_5 * s_),
+            linear_to_srgb(-0.004_196_086_3 * l_ - 0.703_418_614_7 * m_ + 1.707_614_701_0 * s_),
+        )
+    }
+
+    /// Returns the red, green and blue components between `0.0` and `1.0`.
+    fn unit_rgb(&self) -> (f64, f64, f64) {
+        (
+            f64::from(self.r()) / 255.0,
+            f64::from(self.g()) / 255.0,
+            f64::from(self.b()) / 255.0,
+        )
+    }
 }
 
 impl From<i32> for Colour {
@@ -223,6 +822,199 @@ impl From<(u8, u8, u8)> for Colour {
     fn from((r, g, b): (u8, u8, u8)) -> Self { Colour::from_rgb(r, g, b) }
 }
 
+impl FromStr for Colour {
+    type Err = Error;
+
+    /// Parses a colour from the forms that users commonly type.
+    ///
+    /// These are:
+    ///
+    /// - hexadecimal, optionally prefixed with `#` or `0x`, such as `#7289DA`;
+    /// - shorthand hexadecimal prefixed with `#`, such as `#FFF`;
+    /// - CSS functional notation, such as `rgb(114, 137, 218)`;
+    /// - the name of a preset or CSS named colour, via [`by_name`], such as
+    ///   `blurple`, `Dark Teal` or `rebeccapurple`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!("#7289DA".parse::<Colour>().unwrap(), Colour::blurple());
+    /// assert_eq!("0x7289da".parse::<Colour>().unwrap(), Colour::blurple());
+    /// assert_eq!("rgb(114, 137, 218)".parse::<Colour>().unwrap(), Colour::blurple());
+    /// assert_eq!("Blurple".parse::<Colour>().unwrap(), Colour::blurple());
+    /// assert_eq!("#FFF".parse::<Colour>().unwrap(), Colour::new(0xFFFFFF));
+    /// assert!("not a colour".parse::<Colour>().is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::InvalidColour`] if the input is not in any of the
+    /// above forms.
+    ///
+    /// [`ParseError::InvalidColour`]: enum.ParseError.html#variant.InvalidColour
+    /// [`by_name`]: struct.Colour.html#method.by_name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        parse_hex(s)
+            .or_else(|| parse_rgb(s))
+            .or_else(|| Colour::by_name(s))
+            .ok_or_else(|| ParseError::InvalidColour.into())
+    }
+}
+
+/// The D65 white point in CIE XYZ, used by CIELAB.
+const D65: (f64, f64, f64) = (0.950_47, 1.0, 1.088_83);
+
+/// Returns the hue in degrees of RGB components between `0.0` and `1.0`,
+/// given their maximum and the difference from their minimum.
+fn hue(r: f64, g: f64, b: f64, max: f64, delta: f64) -> f64 {
+    let h = if max == r {
+        60.0 * ((g - b) / delta)
+    } else if max == g {
+        60.0 * ((b - r) / delta) + 120.0
+    } else {
+        60.0 * ((r - g) / delta) + 240.0
+    };
+
+    h.rem_euclid(360.0)
+}
+
+/// Converts a hue to a single RGB component, as in the CSS Color Module.
+fn hue_to_rgb(m1: f64, m2: f64, h: f64) -> f64 {
+    let h = h.rem_euclid(1.0);
+
+    if h * 6.0 < 1.0 {
+        m1 + (m2 - m1) * h * 6.0
+    } else if h * 2.0 < 1.0 {
+        m2
+    } else if h * 3.0 < 2.0 {
+        m1 + (m2 - m1) * (2.0 / 3.0 - h) * 6.0
+    } else {
+        m1
+    }
+}
+
+/// Rounds a component to the nearest integer, clamping it to a `u8`.
+///
+/// A small epsilon is added so that values which should be exactly half way
+/// round up, as Sass does, despite floating point error.
+fn round_channel(value: f64) -> u8 {
+    (value + 1e-9).round().clamp(0.0, 255.0) as u8
+}
+
+/// Parses a colour from `#RRGGBB`, `0xRRGGBB`, `RRGGBB` or `#RGB`.
+fn parse_hex(s: &str) -> Option<Colour> {
+    let (hex, prefixed) = match s.strip_prefix('#') {
+        Some(hex) => (hex, true),
+        None => (s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")).unwrap_or(s), false),
+    };
+
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    match hex.len() {
+        6 => u32::from_str_radix(hex, 16).ok().map(Colour),
+        3 if prefixed => {
+            let digit = |idx| u8::from_str_radix(&hex[idx..=idx], 16).ok().map(|d| d * 0x11);
+
+            Some(Colour::from_rgb(digit(0)?, digit(1)?, digit(2)?))
+        },
+        _ => None,
+    }
+}
+
+/// Parses a colour from CSS functional notation, such as `rgb(1, 2, 3)`.
+fn parse_rgb(s: &str) -> Option<Colour> {
+    if !s.get(..4).is_some_and(|prefix| prefix.eq_ignore_ascii_case("rgb(")) {
+        return None;
+    }
+
+    let mut components = s[4..].strip_suffix(')')?.split(',').map(|c| c.trim().parse::<u8>());
+
+    match (components.next(), components.next(), components.next(), components.next()) {
+        (Some(Ok(r)), Some(Ok(g)), Some(Ok(b)), None) => Some(Colour::from_rgb(r, g, b)),
+        _ => None,
+    }
+}
+
+/// Returns the name and Colour of the entry in a table closest to a Colour.
+fn nearest(colour: Colour, table: &'static [(&'static str, u32)]) -> (&'static str, Colour) {
+    let lab = colour.to_oklab();
+
+    table
+        .iter()
+        .map(|&(name, value)| (name, Colour(value)))
+        .min_by(|a, b| {
+            let a = oklab_distance(lab, a.1.to_oklab());
+            let b = oklab_distance(lab, b.1.to_oklab());
+
+            a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+        })
+        .expect("colour tables are not empty")
+}
+
+/// Returns the Euclidean distance between two colours in OKLab.
+fn oklab_distance(a: (f64, f64, f64), b: (f64, f64, f64)) -> f64 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2) + (a.2 - b.2).powi(2)).sqrt()
+}
+
+/// Converts an sRGB component to linear light.
+fn srgb_to_linear(c: u8) -> f64 {
+    let c = f64::from(c) / 255.0;
+
+    if c <= 0.040_45 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+}
+
+/// Converts a linear light component to sRGB.
+fn linear_to_srgb(c: f64) -> u8 {
+    let c = if c <= 0.003_130_8 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 };
+
+    round_channel(c * 255.0)
+}
+
+/// The companding function from CIE XYZ to CIELAB.
+fn lab_f(t: f64) -> f64 {
+    const DELTA: f64 = 6.0 / 29.0;
+
+    if t > DELTA.powi(3) { t.cbrt() } else { t / (3.0 * DELTA * DELTA) + 4.0 / 29.0 }
+}
+
+/// The inverse of [`lab_f`].
+///
+/// [`lab_f`]: fn.lab_f.html
+fn lab_f_inv(t: f64) -> f64 {
+    const DELTA: f64 = 6.0 / 29.0;
+
+    if t > DELTA { t.powi(3) } else { 3.0 * DELTA * DELTA * (t - 4.0 / 29.0) }
+}
+
+/// An iterator over the names and values of the [`Colour`] presets.
+///
+/// This is created by [`Colour::presets`].
+///
+/// [`Colour`]: struct.Colour.html
+/// [`Colour::presets`]: struct.Colour.html#method.presets
+#[derive(Clone, Debug)]
+pub struct Presets {
+    inner: Iter<'static, (&'static str, u32)>,
+}
+
+impl Iterator for Presets {
+    type Item = (&'static str, Colour);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|&(name, value)| (name, Colour(value)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl ExactSizeIterator for Presets {}
+
 colour! {
     /// Creates a new `Colour`, setting its RGB value to `(111, 198, 226)`.
     blitz_blue, 0x6FC6E2;
@@ -0,0 +1,194 @@
+use std::time::Duration;
+use super::error::{ParseError, Result};
+
+/// A unit of a duration, as its number of seconds, compact suffix, and
+/// singular and plural names.
+type Unit = (u64, &'static str, &'static str, &'static str);
+
+/// The units of a duration, from largest to smallest.
+const UNITS: [Unit; 6] = [
+    (365 * 24 * 60 * 60, "y", "year", "years"),
+    (7 * 24 * 60 * 60, "w", "week", "weeks"),
+    (24 * 60 * 60, "d", "day", "days"),
+    (60 * 60, "h", "hour", "hours"),
+    (60, "m", "minute", "minutes"),
+    (1, "s", "second", "seconds"),
+];
+
+/// Parses a human-readable duration, such as those given to moderation
+/// commands.
+///
+/// The duration is made up of one or more numbers, each followed by a unit,
+/// optionally separated by whitespace, commas, or `and`. A number on its own is
+/// a number of seconds.
+///
+/// The units, which ignore case, are:
+///
+/// - `s`, `sec`, `secs`, `second`, `seconds`;
+/// - `m`, `min`, `mins`, `minute`, `minutes`;
+/// - `h`, `hr`, `hrs`, `hour`, `hours`;
+/// - `d`, `day`, `days`;
+/// - `w`, `wk`, `wks`, `week`, `weeks`;
+/// - `y`, `yr`, `yrs`, `year`, `years`, each of 365 days.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::parse_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5400));
+/// assert_eq!(parse_duration("1 week").unwrap(), Duration::from_secs(604800));
+/// assert_eq!(parse_duration("2 days, 3 hours and 5 mins").unwrap(), Duration::from_secs(183900));
+/// assert_eq!(parse_duration("90").unwrap(), Duration::from_secs(90));
+/// assert!(parse_duration("1 fortnight").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidDuration`] if the input is empty, contains an
+/// unknown unit, or is too long to be represented.
+///
+/// [`ParseError::InvalidDuration`]: enum.ParseError.html#variant.InvalidDuration
+pub fn parse_duration(input: &str) -> Result<Duration> {
+    let input = input.trim();
+
+    if !input.is_empty() && input.bytes().all(|b| b.is_ascii_digit()) {
+        return input.parse()
+            .map(Duration::from_secs)
+            .map_err(|_| ParseError::InvalidDuration.into());
+    }
+
+    let mut rest = input;
+    let mut secs = 0u64;
+    let mut parts = 0;
+
+    loop {
+        rest = skip_separators(rest);
+
+        if rest.is_empty() {
+            break;
+        }
+
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let number = rest[..digits].parse::<u64>().map_err(|_| ParseError::InvalidDuration)?;
+        rest = rest[digits..].trim_start();
+
+        let letters = rest.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(rest.len());
+        let unit = unit_secs(&rest[..letters]).ok_or(ParseError::InvalidDuration)?;
+        rest = &rest[letters..];
+
+        secs = number.checked_mul(unit)
+            .and_then(|part| secs.checked_add(part))
+            .ok_or(ParseError::InvalidDuration)?;
+        parts += 1;
+    }
+
+    if parts == 0 {
+        return Err(ParseError::InvalidDuration.into());
+    }
+
+    Ok(Duration::from_secs(secs))
+}
+
+/// Formats a duration compactly, such as `1h30m`.
+///
+/// Fractions of a second are ignored, and a duration of less than a second is
+/// formatted as `0s`. The output can be parsed by [`parse_duration`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::format_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(format_duration(Duration::from_secs(5400)), "1h30m");
+/// assert_eq!(format_duration(Duration::from_secs(694861)), "1w1d1h1m1s");
+/// assert_eq!(format_duration(Duration::from_millis(500)), "0s");
+/// ```
+///
+/// [`parse_duration`]: fn.parse_duration.html
+pub fn format_duration(duration: Duration) -> String {
+    let parts = split(duration);
+
+    if parts.is_empty() {
+        return "0s".to_owned();
+    }
+
+    parts
+        .iter()
+        .map(|&(count, &(_, suffix, _, _))| format!("{}{}", count, suffix))
+        .collect()
+}
+
+/// Formats a duration in words, such as `1 hour and 30 minutes`.
+///
+/// Fractions of a second are ignored, and a duration of less than a second is
+/// formatted as `0 seconds`.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::format_duration_long;
+/// use std::time::Duration;
+///
+/// assert_eq!(format_duration_long(Duration::from_secs(60)), "1 minute");
+/// assert_eq!(format_duration_long(Duration::from_secs(5400)), "1 hour and 30 minutes");
+/// assert_eq!(format_duration_long(Duration::from_secs(183900)), "2 days, 3 hours and 5 minutes");
+/// ```
+pub fn format_duration_long(duration: Duration) -> String {
+    let parts = split(duration)
+        .iter()
+        .map(|&(count, &(_, _, singular, plural))| {
+            format!("{} {}", count, if count == 1 { singular } else { plural })
+        })
+        .collect::<Vec<_>>();
+
+    match parts.split_last() {
+        None => "0 seconds".to_owned(),
+        Some((last, [])) => last.clone(),
+        Some((last, init)) => format!("{} and {}", init.join(", "), last),
+    }
+}
+
+/// Splits a duration into the non-zero counts of each unit.
+fn split(duration: Duration) -> Vec<(u64, &'static Unit)> {
+    let mut secs = duration.as_secs();
+
+    UNITS
+        .iter()
+        .filter_map(|unit| {
+            let count = secs / unit.0;
+            secs %= unit.0;
+
+            if count == 0 { None } else { Some((count, unit)) }
+        })
+        .collect()
+}
+
+/// Skips any whitespace, commas, and `and`s at the start of the input.
+fn skip_separators(mut input: &str) -> &str {
+    loop {
+        input = input.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+
+        match input.get(..3) {
+            Some(word) if word.eq_ignore_ascii_case("and") => input = &input[3..],
+            _ => return input,
+        }
+    }
+}
+
+/// Returns the number of seconds in a unit, ignoring case.
+fn unit_secs(unit: &str) -> Option<u64> {
+    let secs = match &unit.to_lowercase()[..] {
+        "s" | "sec" | "secs" | "second" | "seconds" => 1,
+        "m" | "min" | "mins" | "minute" | "minutes" => UNITS[4].0,
+        "h" | "hr" | "hrs" | "hour" | "hours" => UNITS[3].0,
+        "d" | "day" | "days" => UNITS[2].0,
+        "w" | "wk" | "wks" | "week" | "weeks" => UNITS[1].0,
+        "y" | "yr" | "yrs" | "year" | "years" => UNITS[0].0,
+        _ => return None,
+    };
+
+    Some(secs)
+}
@@ -3,16 +3,36 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::io::Error as IoError;
 use std::result::Result as StdResult;
 
+#[cfg(feature = "image")]
+use image::ImageError;
+
 pub type Result<T> = StdResult<T, Error>;
 
+/// The common error type of the crate.
+///
+/// This is non-exhaustive because the variants available depend on the
+/// enabled features.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     Io(IoError),
+    Parse(ParseError),
+    /// An image could not be decoded, either because its format is not
+    /// supported or because it is malformed.
+    ///
+    /// **Note**: Requires the `image` feature.
+    #[cfg(feature = "image")]
+    Image(ImageError),
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        f.write_str(self.description())
+        match *self {
+            Error::Parse(ref inner) => Display::fmt(inner, f),
+            #[cfg(feature = "image")]
+            Error::Image(ref inner) => write!(f, "Image could not be decoded: {}", inner),
+            _ => f.write_str(self.description()),
+        }
     }
 }
 
@@ -22,6 +42,9 @@ impl StdError for Error {
 
         match *self {
             Io(ref inner) => inner.description(),
+            Parse(ref inner) => inner.as_str(),
+            #[cfg(feature = "image")]
+            Image(_) => "Image could not be decoded",
         }
     }
 }
@@ -31,3 +54,110 @@ impl From<IoError> for Error {
         Error::Io(err)
     }
 }
+
+#[cfg(feature = "image")]
+impl From<ImageError> for Error {
+    fn from(err: ImageError) -> Self {
+        Error::Image(err)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// The reason that a value could not be parsed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ParseError {
+    /// The input did not start with the prefix expected for its kind.
+    WrongPrefix,
+    /// The input did not end with a closing `>`.
+    MissingClosingBracket,
+    /// The input did not contain an Id.
+    EmptyId,
+    /// The Id contained a character that is not a digit.
+    NonNumericId,
+    /// The Id was too large to fit in a `u64`.
+    IdOverflow,
+    /// The Id was `0`, which is never assigned by Discord.
+    ZeroId,
+    /// The name was longer than Discord allows.
+    NameTooLong,
+    /// The name was empty.
+    EmptyName,
+    /// The name contained a character that Discord does not allow in it.
+    InvalidName,
+    /// A command mention had more than a subcommand group and a subcommand.
+    TooManySubcommands,
+    /// A command argument was expected, but there were none left.
+    MissingArgument,
+    /// A command argument was not of the expected form.
+    InvalidArgument,
+    /// A command argument was not a valid integer.
+    InvalidInteger,
+    /// A command argument was not a valid floating point number.
+    InvalidFloat,
+    /// A command argument was not a valid boolean.
+    InvalidBool,
+    /// The input was not a valid colour.
+    InvalidColour,
+    /// The input was not a valid duration.
+    InvalidDuration,
+    /// A flag or option was given that has not been registered, with the byte
+    /// offset of the argument containing it.
+    UnknownOption(usize),
+    /// An option was given without a value, with its long name.
+    MissingOptionValue(&'static str),
+    /// A flag, which does not take a value, was given one, with its long name.
+    UnexpectedOptionValue(&'static str),
+}
+
+impl ParseError {
+    fn as_str(&self) -> &'static str {
+        use self::ParseError::*;
+
+        match *self {
+            WrongPrefix => "Input does not start with the expected prefix",
+            MissingClosingBracket => "Input does not end with a closing '>'",
+            EmptyId => "Input does not contain an Id",
+            NonNumericId => "Id contains a non-numeric character",
+            IdOverflow => "Id is too large",
+            ZeroId => "Id is zero",
+            NameTooLong => "Name is too long",
+            EmptyName => "Name is empty",
+            InvalidName => "Name contains an invalid character",
+            TooManySubcommands => "Command has too many subcommands",
+            MissingArgument => "Argument is missing",
+            InvalidArgument => "Argument is invalid",
+            InvalidInteger => "Argument is not a valid integer",
+            InvalidFloat => "Argument is not a valid number",
+            InvalidBool => "Argument is not a valid boolean",
+            InvalidColour => "Input is not a valid colour",
+            InvalidDuration => "Input is not a valid duration",
+            UnknownOption(_) => "Option is not recognised",
+            MissingOptionValue(_) => "Option is missing a value",
+            UnexpectedOptionValue(_) => "Flag does not take a value",
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        use self::ParseError::*;
+
+        match *self {
+            UnknownOption(offset) => write!(f, "{} at byte {}", self.as_str(), offset),
+            MissingOptionValue(name) |
+            UnexpectedOptionValue(name) => write!(f, "{}: {}", self.as_str(), name),
+            _ => f.write_str(self.as_str()),
+        }
+    }
+}
+
+impl StdError for ParseError {
+    fn description(&self) -> &str {
+        self.as_str()
+    }
+}
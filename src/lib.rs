@@ -2,13 +2,54 @@
 //! fully use the library.
 
 extern crate base64;
+#[cfg(feature = "image")]
+extern crate image;
 extern crate serenity_common;
 
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+
+mod args;
 mod colour;
+mod css;
+mod duration;
 mod error;
-
-pub use self::colour::Colour;
-pub use self::error::{Error, Result};
+mod link;
+mod mention;
+mod options;
+mod palette;
+mod snowflake;
+mod timestamp;
+
+pub use self::args::{
+    Args,
+    ChannelArg,
+    EmojiArg,
+    FromArg,
+    RoleArg,
+    Token,
+    Tokenizer,
+    Tokens,
+    UserArg,
+};
+pub use self::colour::{Colour, Presets};
+pub use self::duration::{format_duration, format_duration_long, parse_duration};
+pub use self::error::{Error, ParseError, Result};
+pub use self::link::{
+    FoundInvite,
+    InviteLink,
+    Invites,
+    MessageLink,
+    find_invites,
+    parse_invite_url,
+    parse_message_link,
+};
+pub use self::mention::{CommandMention, EmojiRef, Mention, Mentions, mentions, parse_mention};
+pub use self::options::{OptionParser, Options};
+pub use self::palette::{Interpolation, gradient, hue_palette};
+pub use self::snowflake::{DISCORD_EPOCH, Snowflake};
+pub use self::timestamp::{Timestamp, TimestampStyle, parse_timestamp};
 
 // Note: Here for BC purposes.
 #[cfg(feature = "builder")]
@@ -20,6 +61,7 @@ use std::ffi::OsStr;
 use std::fs::File;
 use std::hash::Hash;
 use std::io::Read;
+use std::ops::Range;
 use std::path::Path;
 
 #[cfg(feature = "cache")]
@@ -94,6 +136,11 @@ pub fn is_nsfw(name: &str) -> bool {
 
 /// Retrieves the "code" part of an invite out of a URL.
 ///
+/// All forms of invite URLs supported by [`parse_invite_url`] are accepted.
+/// If the input is not an invite URL, then it is assumed to already be a code
+/// and is returned as-is. Use [`parse_invite_url`] to detect input which is
+/// not an invite.
+///
 /// # Examples
 ///
 /// Three formats of [invite][`RichInvite`] codes are supported:
@@ -128,22 +175,29 @@ pub fn is_nsfw(name: &str) -> bool {
 /// assert_eq!(serenity_utils::parse_invite(url), "0cDvIgU2voY8RSYL");
 /// ```
 ///
+/// Longer URLs, including those with a query string, are also supported:
+///
+/// ```rust
+/// use serenity_utils;
+///
+/// let url = "https://discord.com/invite/0cDvIgU2voY8RSYL?event=912345678901234567";
+///
+/// assert_eq!(serenity_utils::parse_invite(url), "0cDvIgU2voY8RSYL");
+/// ```
+///
 /// [`RichInvite`]: ../model/struct.RichInvite.html
+/// [`parse_invite_url`]: fn.parse_invite_url.html
 pub fn parse_invite(code: &str) -> &str {
-    if code.starts_with("https://discord.gg/") {
-        &code[19..]
-    } else if code.starts_with("http://discord.gg/") {
-        &code[18..]
-    } else if code.starts_with("discord.gg/") {
-        &code[11..]
-    } else {
-        code
+    match parse_invite_url(code) {
+        Some(invite) => invite.code,
+        None => code,
     }
 }
 
 /// Retreives an Id from a user mention.
 ///
-/// If the mention is invalid, then `None` is returned.
+/// If the mention is invalid, then `None` is returned. Use
+/// [`try_parse_username`] to retrieve the reason that a mention is invalid.
 ///
 /// # Examples
 ///
@@ -166,28 +220,68 @@ pub fn parse_invite(code: &str) -> &str {
 ///
 /// assert!(parse_username("<@1149413154aa17899012").is_none());
 /// assert!(parse_username("<@!11494131541789a90b1c2").is_none());
+/// assert!(parse_username("<@114941315417899012x").is_none());
 /// ```
 ///
 /// [`User`]: ../model/struct.User.html
+/// [`try_parse_username`]: fn.try_parse_username.html
 pub fn parse_username(mention: &str) -> Option<u64> {
-    if mention.len() < 4 {
-        return None;
-    }
+    try_parse_username(mention).ok()
+}
 
+/// Retreives an Id from a user mention, returning the reason that the mention
+/// is invalid on failure.
+///
+/// Both the `<@id>` and nickname `<@!id>` forms are accepted. The mention must
+/// end with a `>` directly after the Id, and the Id must be a non-zero `u64`.
+///
+/// # Errors
+///
+/// Returns an [`Error::Parse`] describing the first part of the mention that
+/// is invalid.
+///
+/// # Examples
+///
+/// Retrieving an Id from a valid [`User`] mention:
+///
+/// ```rust
+/// use serenity_utils::try_parse_username;
+///
+/// assert_eq!(try_parse_username("<@!114941315417899012>").unwrap(), 114941315417899012);
+/// ```
+///
+/// Determining why a mention is invalid:
+///
+/// ```rust
+/// use serenity_utils::{Error, ParseError, try_parse_username};
+///
+/// match try_parse_username("<@114941315417899012x") {
+///     Err(Error::Parse(ParseError::MissingClosingBracket)) => {},
+///     _ => panic!("mention should be missing its closing bracket"),
+/// }
+///
+/// match try_parse_username("<@99999999999999999999>") {
+///     Err(Error::Parse(ParseError::IdOverflow)) => {},
+///     _ => panic!("Id should overflow"),
+/// }
+/// ```
+///
+/// [`Error::Parse`]: enum.Error.html#variant.Parse
+/// [`User`]: ../model/struct.User.html
+pub fn try_parse_username(mention: &str) -> Result<u64> {
     if mention.starts_with("<@!") {
-        let len = mention.len() - 1;
-        mention[3..len].parse::<u64>().ok()
-    } else if mention.starts_with("<@") {
-        let len = mention.len() - 1;
-        mention[2..len].parse::<u64>().ok()
+        parse_prefixed_id(mention, "<@!")
+    } else if mention.starts_with("<@&") {
+        Err(ParseError::WrongPrefix.into())
     } else {
-        None
+        parse_prefixed_id(mention, "<@")
     }
 }
 
 /// Retreives an Id from a role mention.
 ///
-/// If the mention is invalid, then `None` is returned.
+/// If the mention is invalid, then `None` is returned. Use [`try_parse_role`]
+/// to retrieve the reason that a mention is invalid.
 ///
 /// # Examples
 ///
@@ -208,22 +302,41 @@ pub fn parse_username(mention: &str) -> Option<u64> {
 /// ```
 ///
 /// [`Role`]: ../model/struct.Role.html
+/// [`try_parse_role`]: fn.try_parse_role.html
 pub fn parse_role(mention: &str) -> Option<u64> {
-    if mention.len() < 4 {
-        return None;
-    }
+    try_parse_role(mention).ok()
+}
 
-    if mention.starts_with("<@&") && mention.ends_with('>') {
-        let len = mention.len() - 1;
-        mention[3..len].parse::<u64>().ok()
-    } else {
-        None
-    }
+/// Retreives an Id from a role mention, returning the reason that the mention
+/// is invalid on failure.
+///
+/// # Errors
+///
+/// Returns an [`Error::Parse`] describing the first part of the mention that
+/// is invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{Error, ParseError, try_parse_role};
+///
+/// assert_eq!(try_parse_role("<@&136107769680887808>").unwrap(), 136107769680887808);
+///
+/// match try_parse_role("<@136107769680887808>") {
+///     Err(Error::Parse(ParseError::WrongPrefix)) => {},
+///     _ => panic!("mention should have the wrong prefix"),
+/// }
+/// ```
+///
+/// [`Error::Parse`]: enum.Error.html#variant.Parse
+pub fn try_parse_role(mention: &str) -> Result<u64> {
+    parse_prefixed_id(mention, "<@&")
 }
 
 /// Retreives an Id from a channel mention.
 ///
-/// If the channel mention is invalid, then `None` is returned.
+/// If the channel mention is invalid, then `None` is returned. Use
+/// [`try_parse_channel`] to retrieve the reason that a mention is invalid.
 ///
 /// # Examples
 ///
@@ -245,64 +358,270 @@ pub fn parse_role(mention: &str) -> Option<u64> {
 /// ```
 ///
 /// [`Channel`]: ../model/enum.Channel.html
+/// [`try_parse_channel`]: fn.try_parse_channel.html
 pub fn parse_channel(mention: &str) -> Option<u64> {
-    if mention.len() < 4 {
-        return None;
-    }
+    try_parse_channel(mention).ok()
+}
 
-    if mention.starts_with("<#") && mention.ends_with('>') {
-        let len = mention.len() - 1;
-        mention[2..len].parse::<u64>().ok()
-    } else {
-        None
-    }
+/// Retreives an Id from a channel mention, returning the reason that the
+/// mention is invalid on failure.
+///
+/// # Errors
+///
+/// Returns an [`Error::Parse`] describing the first part of the mention that
+/// is invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{Error, ParseError, try_parse_channel};
+///
+/// assert_eq!(try_parse_channel("<#81384788765712384>").unwrap(), 81384788765712384);
+///
+/// match try_parse_channel("<#81384788765712384") {
+///     Err(Error::Parse(ParseError::MissingClosingBracket)) => {},
+///     _ => panic!("mention should be missing its closing bracket"),
+/// }
+/// ```
+///
+/// [`Error::Parse`]: enum.Error.html#variant.Parse
+pub fn try_parse_channel(mention: &str) -> Result<u64> {
+    parse_prefixed_id(mention, "<#")
 }
 
-/// Retreives the name and Id from an emoji mention.
+/// Retreives the name and Id from an emoji mention, as well as whether the
+/// emoji is animated.
 ///
-/// If the emoji usage is invalid, then `None` is returned.
+/// Both static (`<:name:id>`) and animated (`<a:name:id>`) emoji usages are
+/// supported.
+///
+/// If the emoji usage is invalid, then `None` is returned. Use
+/// [`try_parse_emoji`] to retrieve the reason that an emoji usage is invalid.
 ///
 /// # Examples
 ///
 /// Ensure that a valid [`Emoji`] usage is correctly parsed:
 ///
 /// ```rust
-/// use serenity_utils::parse_emoji;
+/// use serenity_utils::{EmojiRef, parse_emoji};
 ///
-/// let expected = Some(("smugAnimeFace", 302516740095606785u64));
+/// let expected = Some(EmojiRef {
+///     animated: false,
+///     name: "smugAnimeFace",
+///     id: 302516740095606785,
+/// });
 ///
 /// assert_eq!(parse_emoji("<:smugAnimeFace:302516740095606785>"), expected);
 /// ```
 ///
+/// Animated emoji usages are also parsed:
+///
+/// ```rust
+/// use serenity_utils::parse_emoji;
+///
+/// let emoji = parse_emoji("<a:blobDance:302516740095606785>").unwrap();
+///
+/// assert!(emoji.animated);
+/// assert_eq!(emoji.name, "blobDance");
+/// ```
+///
 /// Asserting that an invalid emoji usage returns `None`:
 ///
 /// ```rust
 /// use serenity_utils::parse_emoji;
 ///
 /// assert!(parse_emoji("<:smugAnimeFace:302516740095606785").is_none());
+/// assert!(parse_emoji("<b:smugAnimeFace:302516740095606785>").is_none());
+/// assert!(parse_emoji("<:smug face:302516740095606785>").is_none());
 /// ```
 ///
 /// [`Emoji`]: ../model/struct.Emoji.html
-pub fn parse_emoji(mention: &str) -> Option<(&str, u64)> {
-    let len = mention.len();
+/// [`try_parse_emoji`]: fn.try_parse_emoji.html
+pub fn parse_emoji<'a>(mention: &'a str) -> Option<EmojiRef<'a>> {
+    try_parse_emoji(mention).ok()
+}
 
-    if len < 6 || len > 56 {
-        return None;
+/// Retreives the name and Id from an emoji mention, returning the reason that
+/// the emoji usage is invalid on failure.
+///
+/// Emoji names must be between 1 and 32 characters long, and may only contain
+/// ASCII letters, digits and underscores.
+///
+/// # Errors
+///
+/// Returns an [`Error::Parse`] describing the first part of the emoji usage
+/// that is invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{Error, ParseError, try_parse_emoji};
+///
+/// let emoji = try_parse_emoji("<:smugAnimeFace:302516740095606785>").unwrap();
+///
+/// assert_eq!(emoji.name, "smugAnimeFace");
+///
+/// match try_parse_emoji("<::302516740095606785>") {
+///     Err(Error::Parse(ParseError::EmptyName)) => {},
+///     _ => panic!("emoji name should be empty"),
+/// }
+/// ```
+///
+/// [`Error::Parse`]: enum.Error.html#variant.Parse
+pub fn try_parse_emoji<'a>(mention: &'a str) -> Result<EmojiRef<'a>> {
+    let (animated, rest) = if let Some(rest) = mention.strip_prefix("<:") {
+        (false, rest)
+    } else if let Some(rest) = mention.strip_prefix("<a:") {
+        (true, rest)
+    } else {
+        return Err(ParseError::WrongPrefix.into());
+    };
+
+    let rest = match rest.strip_suffix('>') {
+        Some(rest) => rest,
+        None => return Err(ParseError::MissingClosingBracket.into()),
+    };
+
+    let (name, id) = match rest.find(':') {
+        Some(pos) => (&rest[..pos], &rest[pos + 1..]),
+        None => (rest, ""),
+    };
+
+    if name.is_empty() {
+        return Err(ParseError::EmptyName.into());
     }
 
-    if mention.starts_with("<:") && mention.ends_with('>') {
-        let (name_to, id) = match mention[2..].find(':') {
-            Some(pos) => (pos + 2, &mention[pos + 3..len - 1]),
-            None => return None,
-        };
+    if name.chars().count() > 32 {
+        return Err(ParseError::NameTooLong.into());
+    }
+
+    if !name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') {
+        return Err(ParseError::InvalidName.into());
+    }
+
+    Ok(EmojiRef {
+        animated,
+        name,
+        id: parse_id(id)?,
+    })
+}
+
+/// Retreives the name, subcommand group, subcommand, and Id from an
+/// application command mention.
+///
+/// Mentions of commands (`</name:id>`), subcommands (`</name subcommand:id>`),
+/// and subcommands within groups (`</name group subcommand:id>`) are
+/// supported.
+///
+/// If the command mention is invalid, then `None` is returned. Use
+/// [`try_parse_command`] to retrieve the reason that a mention is invalid.
+///
+/// # Examples
+///
+/// Ensure that a valid command mention is correctly parsed:
+///
+/// ```rust
+/// use serenity_utils::{CommandMention, parse_command};
+///
+/// let expected = Some(CommandMention {
+///     name: "ban",
+///     subcommand_group: None,
+///     subcommand: Some("user"),
+///     id: 1009175624553680926,
+/// });
+///
+/// assert_eq!(parse_command("</ban user:1009175624553680926>"), expected);
+/// ```
+///
+/// Asserting that an invalid command mention returns `None`:
+///
+/// ```rust
+/// use serenity_utils::parse_command;
+///
+/// assert!(parse_command("</ban user:1009175624553680926").is_none());
+/// assert!(parse_command("</ban:>").is_none());
+/// ```
+///
+/// [`try_parse_command`]: fn.try_parse_command.html
+pub fn parse_command<'a>(mention: &'a str) -> Option<CommandMention<'a>> {
+    try_parse_command(mention).ok()
+}
+
+/// Retreives the name, subcommand group, subcommand, and Id from an
+/// application command mention, returning the reason that the mention is
+/// invalid on failure.
+///
+/// Each name must be between 1 and 32 characters long.
+///
+/// # Errors
+///
+/// Returns an [`Error::Parse`] describing the first part of the mention that
+/// is invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{Error, ParseError, try_parse_command};
+///
+/// let command = try_parse_command("</permissions user get:1009175624553680926>").unwrap();
+///
+/// assert_eq!(command.name, "permissions");
+/// assert_eq!(command.subcommand_group, Some("user"));
+/// assert_eq!(command.subcommand, Some("get"));
+///
+/// match try_parse_command("</a b c d:1009175624553680926>") {
+///     Err(Error::Parse(ParseError::TooManySubcommands)) => {},
+///     _ => panic!("mention should have too many subcommands"),
+/// }
+/// ```
+///
+/// [`Error::Parse`]: enum.Error.html#variant.Parse
+pub fn try_parse_command<'a>(mention: &'a str) -> Result<CommandMention<'a>> {
+    let rest = match mention.strip_prefix("</") {
+        Some(rest) => rest,
+        None => return Err(ParseError::WrongPrefix.into()),
+    };
+
+    let rest = match rest.strip_suffix('>') {
+        Some(rest) => rest,
+        None => return Err(ParseError::MissingClosingBracket.into()),
+    };
+
+    let (names, id) = match rest.find(':') {
+        Some(pos) => (&rest[..pos], &rest[pos + 1..]),
+        None => (rest, ""),
+    };
 
-        match id.parse::<u64>() {
-            Ok(x) => Some((&mention[2..name_to], x)),
-            Err(_) => None,
+    let mut parts = [None; 3];
+
+    for (i, part) in names.split(' ').enumerate() {
+        if i >= parts.len() {
+            return Err(ParseError::TooManySubcommands.into());
         }
-    } else {
-        None
+
+        if part.is_empty() {
+            return Err(ParseError::EmptyName.into());
+        }
+
+        if part.chars().count() > 32 {
+            return Err(ParseError::NameTooLong.into());
+        }
+
+        parts[i] = Some(part);
     }
+
+    let (subcommand_group, subcommand) = match parts {
+        [_, Some(group), Some(subcommand)] => (Some(group), Some(subcommand)),
+        [_, subcommand, None] => (None, subcommand),
+        _ => unreachable!(),
+    };
+
+    Ok(CommandMention {
+        name: parts[0].unwrap_or_default(),
+        subcommand_group,
+        subcommand,
+        id: parse_id(id)?,
+    })
 }
 
 /// Reads an image from a path and encodes it into base64.
@@ -336,9 +655,92 @@ pub fn read_image<P: AsRef<Path>>(path: P) -> Result<String> {
     Ok(format!("data:image/{};base64,{}", ext, b64))
 }
 
+/// Decodes a PNG, JPEG, GIF or WebP image and returns its dominant colour.
+///
+/// This is the most common colour of the image's [`colour_palette`], which is
+/// useful for setting the accent colour of an embed from a user's avatar or a
+/// guild's icon. Transparent pixels are ignored, and an entirely transparent
+/// image has a dominant colour of `Colour::default()`.
+///
+/// **Note**: Requires the `image` feature.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::fs;
+///
+/// let avatar = fs::read("./avatar.png").expect("Failed to read image");
+/// let colour = serenity_utils::dominant_colour(&avatar)
+///     .expect("Failed to decode image");
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::Image`] if the image could not be decoded.
+///
+/// [`Error::Image`]: enum.Error.html#variant.Image
+/// [`colour_palette`]: fn.colour_palette.html
+#[cfg(feature = "image")]
+pub fn dominant_colour(bytes: &[u8]) -> Result<Colour> {
+    Ok(colour_palette(bytes, 5)?.first().cloned().unwrap_or_default())
+}
+
+/// Decodes a PNG, JPEG, GIF or WebP image and returns a palette of up to
+/// `count` of its most representative colours, from most to least common.
+///
+/// The colours are found by k-means clustering of the image's pixels in the
+/// OKLab colour space, with a deterministic initialisation so that the same
+/// image always produces the same palette. Large images are downscaled first,
+/// and transparent pixels are ignored.
+///
+/// Fewer colours are returned if the image has fewer distinct colours, and
+/// none if it is entirely transparent.
+///
+/// **Note**: Requires the `image` feature.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::fs;
+///
+/// let icon = fs::read("./icon.png").expect("Failed to read image");
+/// let palette = serenity_utils::colour_palette(&icon, 4)
+///     .expect("Failed to decode image");
+///
+/// assert!(palette.len() <= 4);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::Image`] if the image could not be decoded.
+///
+/// [`Error::Image`]: enum.Error.html#variant.Image
+#[cfg(feature = "image")]
+pub fn colour_palette(bytes: &[u8], count: usize) -> Result<Vec<Colour>> {
+    const MAX_SIZE: u32 = 64;
+
+    let mut image = image::load_from_memory(bytes)?;
+
+    if image.width() > MAX_SIZE || image.height() > MAX_SIZE {
+        image = image.thumbnail(MAX_SIZE, MAX_SIZE);
+    }
+
+    let pixels = image
+        .to_rgba8()
+        .pixels()
+        .filter(|pixel| pixel[3] >= 128)
+        .map(|pixel| Colour::from_rgb(pixel[0], pixel[1], pixel[2]))
+        .collect::<Vec<_>>();
+
+    Ok(palette::kmeans(&pixels, count))
+}
+
 /// Turns a string into a vector of string arguments, splitting by spaces, but
 /// parsing content within quotes as one individual argument.
 ///
+/// This is a shorthand for splitting with the default configuration of a
+/// [`Tokenizer`], which can be used to customise how arguments are split.
+///
 /// # Examples
 ///
 /// Parsing two quoted commands:
@@ -365,50 +767,36 @@ pub fn read_image<P: AsRef<Path>>(path: P) -> Result<String> {
 ///
 /// assert_eq!(parse_quotes(command), expected);
 /// ```
+///
+/// [`Tokenizer`]: struct.Tokenizer.html
 pub fn parse_quotes(s: &str) -> Vec<String> {
-    let mut args = vec![];
-    let mut in_string = false;
-    let mut escaping = false;
-    let mut current_str = String::default();
-
-    for x in s.chars() {
-        if in_string {
-            if x == '\\' && !escaping {
-                escaping = true;
-            } else if x == '"' && !escaping {
-                if !current_str.is_empty() {
-                    args.push(current_str);
-                }
-
-                current_str = String::default();
-                in_string = false;
-            } else {
-                current_str.push(x);
-                escaping = false;
-            }
-        } else if x == ' ' {
-            if !current_str.is_empty() {
-                args.push(current_str.clone());
-            }
-
-            current_str = String::default();
-        } else if x == '"' {
-            if !current_str.is_empty() {
-                args.push(current_str.clone());
-            }
-
-            in_string = true;
-            current_str = String::default();
-        } else {
-            current_str.push(x);
-        }
-    }
-
-    if !current_str.is_empty() {
-        args.push(current_str);
-    }
+    args::QUOTES_TOKENIZER.split(s)
+}
 
-    args
+/// Creates an iterator over the arguments of a string, split in the same way
+/// as [`parse_quotes`].
+///
+/// Arguments are borrowed from the string unless an escaped character
+/// requires that an argument be copied, and are yielded along with their
+/// positions within the string.
+///
+/// # Examples
+///
+/// Pointing to the position of an invalid argument:
+///
+/// ```rust
+/// use serenity_utils::parse_quotes_iter;
+///
+/// let command = r#"ban "some user" 7x"#;
+/// let days = parse_quotes_iter(command).nth(2).unwrap();
+///
+/// assert_eq!(days.value, "7x");
+/// assert_eq!(days.offset, 16);
+/// ```
+///
+/// [`parse_quotes`]: fn.parse_quotes.html
+pub fn parse_quotes_iter<'a>(s: &'a str) -> Tokens<'static, 'a> {
+    args::QUOTES_TOKENIZER.tokens(s)
 }
 
 /// Calculates the Id of the shard responsible for a guild, given its Id and
@@ -427,6 +815,159 @@ pub fn parse_quotes(s: &str) -> Vec<String> {
 #[inline]
 pub fn shard_id(guild_id: u64, shard_count: u64) -> u64 { (guild_id >> 22) % shard_count }
 
+/// Determines whether a shard Id is valid for a total number of shards.
+///
+/// A shard Id is valid if it is less than the total number of shards, which
+/// must be at least 1.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils;
+///
+/// assert!(serenity_utils::is_valid_shard(16, 17));
+/// assert!(!serenity_utils::is_valid_shard(17, 17));
+/// assert!(!serenity_utils::is_valid_shard(0, 0));
+/// ```
+#[inline]
+pub fn is_valid_shard(shard_id: u64, shard_count: u64) -> bool { shard_id < shard_count }
+
+/// Calculates the range of shard Ids that a cluster is responsible for, given
+/// its Id, the total number of clusters, and the total number of shards.
+///
+/// Shards are split as evenly as possible in contiguous ranges, with the first
+/// clusters receiving one extra shard when the shards do not divide evenly.
+/// When there are more clusters than shards, the last clusters receive an
+/// empty range.
+///
+/// Returns `None` if the cluster Id is not less than the number of clusters.
+///
+/// # Examples
+///
+/// Split 10 shards across 3 clusters:
+///
+/// ```rust
+/// use serenity_utils;
+///
+/// assert_eq!(serenity_utils::shard_range(0, 3, 10), Some(0..4));
+/// assert_eq!(serenity_utils::shard_range(1, 3, 10), Some(4..7));
+/// assert_eq!(serenity_utils::shard_range(2, 3, 10), Some(7..10));
+/// assert_eq!(serenity_utils::shard_range(3, 3, 10), None);
+/// ```
+pub fn shard_range(cluster_id: u64, cluster_count: u64, shard_count: u64) -> Option<Range<u64>> {
+    if cluster_id >= cluster_count {
+        return None;
+    }
+
+    let base = shard_count / cluster_count;
+    let extra = shard_count % cluster_count;
+
+    let start = cluster_id * base + cluster_id.min(extra);
+    let len = if cluster_id < extra { base + 1 } else { base };
+
+    Some(start..start + len)
+}
+
+/// Calculates the Id of the cluster responsible for a shard, given the total
+/// number of shards and clusters.
+///
+/// This is the inverse of [`shard_range`].
+///
+/// Returns `None` if the shard Id is not valid for the number of shards, as
+/// determined by [`is_valid_shard`], or if there are no clusters.
+///
+/// # Examples
+///
+/// Find the cluster running shard 5, with 10 shards split across 3 clusters:
+///
+/// ```rust
+/// use serenity_utils;
+///
+/// assert_eq!(serenity_utils::cluster_id(5, 10, 3), Some(1));
+/// assert_eq!(serenity_utils::cluster_id(10, 10, 3), None);
+/// ```
+///
+/// [`is_valid_shard`]: fn.is_valid_shard.html
+/// [`shard_range`]: fn.shard_range.html
+pub fn cluster_id(shard_id: u64, shard_count: u64, cluster_count: u64) -> Option<u64> {
+    if !is_valid_shard(shard_id, shard_count) || cluster_count == 0 {
+        return None;
+    }
+
+    let base = shard_count / cluster_count;
+    let extra = shard_count % cluster_count;
+    let larger = (base + 1) * extra;
+
+    // When there are fewer shards than clusters, every shard is in one of the
+    // larger clusters, so `base` is never divided by while it is `0`.
+    if shard_id < larger {
+        Some(shard_id / (base + 1))
+    } else {
+        Some(extra + (shard_id - larger) / base)
+    }
+}
+
+/// Calculates the identify rate limit bucket of a shard, given the
+/// `max_concurrency` of the bot's session start limit.
+///
+/// Shards in the same bucket must identify one after another, while shards in
+/// different buckets may identify concurrently.
+///
+/// # Panics
+///
+/// Panics if `max_concurrency` is `0`.
+///
+/// # Examples
+///
+/// With a `max_concurrency` of 16, shards 3 and 19 share a bucket:
+///
+/// ```rust
+/// use serenity_utils;
+///
+/// assert_eq!(serenity_utils::identify_bucket(3, 16), 3);
+/// assert_eq!(serenity_utils::identify_bucket(19, 16), 3);
+/// ```
+#[inline]
+pub fn identify_bucket(shard_id: u64, max_concurrency: u64) -> u64 { shard_id % max_concurrency }
+
+/// Parses a mention made of a prefix, an Id, and a closing `>`.
+fn parse_prefixed_id(mention: &str, prefix: &str) -> Result<u64> {
+    let id = match mention.strip_prefix(prefix) {
+        Some(id) => id,
+        None => return Err(ParseError::WrongPrefix.into()),
+    };
+
+    match id.strip_suffix('>') {
+        Some(id) => parse_id(id),
+        None => Err(ParseError::MissingClosingBracket.into()),
+    }
+}
+
+/// Parses the Id portion of a mention, ensuring that it is a non-zero `u64`.
+fn parse_id(id: &str) -> Result<u64> {
+    match parse_u64(id)? {
+        0 => Err(ParseError::ZeroId.into()),
+        value => Ok(value),
+    }
+}
+
+/// Parses a string consisting solely of digits into a `u64`.
+fn parse_u64(id: &str) -> Result<u64> {
+    if id.is_empty() {
+        return Err(ParseError::EmptyId.into());
+    }
+
+    if !id.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseError::NonNumericId.into());
+    }
+
+    let value = id.bytes().try_fold(0u64, |value, b| {
+        value.checked_mul(10)?.checked_add(u64::from(b - b'0'))
+    });
+
+    value.ok_or_else(|| ParseError::IdOverflow.into())
+}
+
 /// A function for doing automatic `read`ing (and the releasing of the guard as well)
 /// This is particularly useful if you just want to use the cache for this one time,
 /// or don't want to be messing with the `RwLock` directly.
@@ -0,0 +1,485 @@
+use std::ops::Range;
+use super::parse_id;
+
+/// The hosts that the Discord client serves links from, for each of its
+/// release channels.
+const CLIENT_HOSTS: &[&str] = &[
+    "discord.com",
+    "discordapp.com",
+    "ptb.discord.com",
+    "ptb.discordapp.com",
+    "canary.discord.com",
+    "canary.discordapp.com",
+];
+
+/// An invite parsed from an invite URL.
+///
+/// This is created via [`parse_invite_url`].
+///
+/// [`parse_invite_url`]: fn.parse_invite_url.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct InviteLink<'a> {
+    /// The code of the invite.
+    pub code: &'a str,
+    /// The Id of the guild scheduled event that the invite links to, if any.
+    pub event_id: Option<u64>,
+}
+
+/// Retrieves the code of an invite, and the Id of the scheduled event it links
+/// to if any, out of an invite URL.
+///
+/// The following forms of invite URLs are supported, with or without a
+/// scheme, with or without a `www.` subdomain:
+///
+/// - `discord.gg/code`
+/// - `discord.com/invite/code`
+/// - `discordapp.com/invite/code`
+///
+/// The URL may have a trailing slash, a query string, and may be wrapped in
+/// `<>` as is done to suppress embeds. If the query string has more than one
+/// `event`, the first valid one is used.
+///
+/// If the input is not an invite URL, then `None` is returned.
+///
+/// # Examples
+///
+/// Retrieving the code and event Id out of an invite URL:
+///
+/// ```rust
+/// use serenity_utils::{InviteLink, parse_invite_url};
+///
+/// let url = "https://discord.gg/0cDvIgU2voY8RSYL?event=912345678901234567";
+///
+/// assert_eq!(parse_invite_url(url), Some(InviteLink {
+///     code: "0cDvIgU2voY8RSYL",
+///     event_id: Some(912345678901234567),
+/// }));
+/// ```
+///
+/// Other forms of invite URLs are also supported:
+///
+/// ```rust
+/// use serenity_utils::parse_invite_url;
+///
+/// let invite = parse_invite_url("<https://discord.com/invite/0cDvIgU2voY8RSYL/>").unwrap();
+///
+/// assert_eq!(invite.code, "0cDvIgU2voY8RSYL");
+/// assert_eq!(invite.event_id, None);
+/// ```
+///
+/// Asserting that input which is not an invite URL returns `None`:
+///
+/// ```rust
+/// use serenity_utils::parse_invite_url;
+///
+/// assert!(parse_invite_url("0cDvIgU2voY8RSYL").is_none());
+/// assert!(parse_invite_url("https://discord.com/channels/1/2/3").is_none());
+/// ```
+pub fn parse_invite_url<'a>(url: &'a str) -> Option<InviteLink<'a>> {
+    let url = match url.strip_prefix('<').and_then(|url| url.strip_suffix('>')) {
+        Some(url) => url,
+        None => url,
+    };
+
+    let path = strip_invite_host(url)?;
+    let (invite, len) = read_invite(path)?;
+    let rest = &path[len..];
+
+    if rest.is_empty() || rest.starts_with('#') {
+        Some(invite)
+    } else {
+        None
+    }
+}
+
+/// An invite found within a piece of text via [`find_invites`].
+///
+/// [`find_invites`]: fn.find_invites.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FoundInvite {
+    /// The code of the invite.
+    pub code: String,
+    /// The Id of the guild scheduled event that the invite links to, if any.
+    pub event_id: Option<u64>,
+    /// The byte range of the invite URL within the original text.
+    pub span: Range<usize>,
+}
+
+/// An iterator over every invite found within a piece of text.
+///
+/// This is created via [`find_invites`].
+///
+/// [`find_invites`]: fn.find_invites.html
+#[derive(Clone, Debug)]
+pub struct Invites {
+    original: String,
+    text: String,
+    starts: Vec<usize>,
+    ends: Vec<usize>,
+    pos: usize,
+}
+
+impl Iterator for Invites {
+    type Item = FoundInvite;
+
+    fn next(&mut self) -> Option<FoundInvite> {
+        while let Some(offset) = find_ignore_case(&self.text[self.pos..], "discord") {
+            let host = self.pos + offset;
+            self.pos = host + 1;
+
+            let start = url_start(&self.text, host);
+
+            if !self.is_boundary(start) {
+                continue;
+            }
+
+            let path = match strip_invite_host(&self.text[start..]) {
+                Some(path) => path,
+                None => continue,
+            };
+            let path_start = self.text.len() - path.len();
+
+            if let Some((invite, len)) = read_invite(path) {
+                let end = path_start + len;
+                self.pos = end;
+
+                return Some(FoundInvite {
+                    code: invite.code.to_owned(),
+                    event_id: invite.event_id,
+                    span: self.starts[start]..self.ends[end - 1],
+                });
+            }
+        }
+
+        self.pos = self.text.len();
+
+        None
+    }
+}
+
+/// Finds every invite URL embedded anywhere within a piece of text, such as
+/// the content of a message.
+///
+/// All forms of invite URLs supported by [`parse_invite_url`] are found, even
+/// if they are not separated from the surrounding text by whitespace.
+///
+/// Before searching, the text is normalised to undo common tricks used to
+/// evade invite filters:
+///
+/// - zero-width and other invisible characters are removed;
+/// - markdown formatting characters (`*`, `_`, `~`, `` ` ``, and `|`) are
+///   removed;
+/// - whitespace next to a `.` or `/` is removed, such as in `discord .gg/`;
+/// - bracketed dots, such as in `discord(.)gg/`, are treated as dots.
+///
+/// The span of each invite refers to the original text, covering the
+/// characters which made up the invite before normalisation.
+///
+/// # Examples
+///
+/// Finding invites in a message, including one wrapped in a markdown link:
+///
+/// ```rust
+/// use serenity_utils::find_invites;
+///
+/// let content = "join discord.gg/abc or [here](https://discord.com/invite/def)!";
+/// let codes = find_invites(content).map(|invite| invite.code).collect::<Vec<_>>();
+///
+/// assert_eq!(codes, vec!["abc", "def"]);
+/// ```
+///
+/// Finding an obfuscated invite, and retrieving its span in the original
+/// text:
+///
+/// ```rust
+/// use serenity_utils::find_invites;
+///
+/// let content = "come to discord . gg / **a\u{200B}bc** now";
+/// let invite = find_invites(content).next().unwrap();
+///
+/// assert_eq!(invite.code, "abc");
+/// assert_eq!(&content[invite.span], "discord . gg / **a\u{200B}bc");
+/// ```
+///
+/// [`parse_invite_url`]: fn.parse_invite_url.html
+pub fn find_invites(text: &str) -> Invites {
+    let mut invites = Invites {
+        original: text.to_owned(),
+        text: String::with_capacity(text.len()),
+        starts: Vec::with_capacity(text.len()),
+        ends: Vec::with_capacity(text.len()),
+        pos: 0,
+    };
+
+    let chars = text.char_indices()
+        .filter(|&(_, c)| !is_ignored(c))
+        .collect::<Vec<_>>();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (start, c) = chars[i];
+
+        if (c == '(' || c == '[') && i + 2 < chars.len() && chars[i + 1].1 == '.' {
+            let close = if c == '(' { ')' } else { ']' };
+
+            if chars[i + 2].1 == close {
+                let (end, _) = chars[i + 2];
+                invites.push('.', start, end + 1);
+                i += 3;
+
+                continue;
+            }
+        }
+
+        if c.is_whitespace() {
+            let next = chars[i..].iter().map(|&(_, c)| c).find(|c| !c.is_whitespace());
+            let beside_separator = invites.text.ends_with(&['.', '/'][..]) ||
+                                   next == Some('.') ||
+                                   next == Some('/') ||
+                                   next == Some('(') ||
+                                   next == Some('[');
+
+            if beside_separator {
+                i += 1;
+
+                continue;
+            }
+        }
+
+        invites.push(c, start, start + c.len_utf8());
+        i += 1;
+    }
+
+    invites
+}
+
+impl Invites {
+    /// Whether a URL may start at a position of the normalised text, rather
+    /// than it being part of a longer host or word.
+    ///
+    /// A `.` only separates the URL from a preceding host if there was no
+    /// whitespace after it in the original text, so that an invite following
+    /// the end of a sentence is still found.
+    fn is_boundary(&self, start: usize) -> bool {
+        let before = &self.text[..start];
+
+        if before.ends_with(|c: char| c.is_alphanumeric() || c == '-') {
+            return false;
+        }
+
+        !before.ends_with('.') || self.original[..self.starts[start]]
+            .trim_end_matches(is_ignored)
+            .ends_with(char::is_whitespace)
+    }
+
+    fn push(&mut self, c: char, start: usize, end: usize) {
+        self.text.push(c);
+
+        for _ in 0..c.len_utf8() {
+            self.starts.push(start);
+            self.ends.push(end);
+        }
+    }
+}
+
+/// Whether a character is removed from text before searching it for invites.
+fn is_ignored(c: char) -> bool {
+    matches!(c, '\u{00AD}' | '\u{200B}'..='\u{200F}' | '\u{2060}'..='\u{2064}' | '\u{FEFF}' |
+                '*' | '_' | '~' | '`' | '|')
+}
+
+/// Finds the start of the URL whose host starts at the given position, taking
+/// into account an optional scheme, `www.` subdomain, and testing client
+/// subdomain.
+fn url_start(text: &str, host: usize) -> usize {
+    let mut start = host;
+
+    // The subdomains of the testing clients, as in `CLIENT_HOSTS`.
+    for subdomain in &["ptb.", "canary."] {
+        if ends_with_ignore_case(&text[..start], subdomain) {
+            start -= subdomain.len();
+
+            break;
+        }
+    }
+
+    if ends_with_ignore_case(&text[..start], "www.") {
+        start -= 4;
+    }
+
+    if ends_with_ignore_case(&text[..start], "https://") {
+        start -= 8;
+    } else if ends_with_ignore_case(&text[..start], "http://") {
+        start -= 7;
+    }
+
+    start
+}
+
+/// The Ids referenced by a link to a message.
+///
+/// This is created via [`parse_message_link`].
+///
+/// [`parse_message_link`]: fn.parse_message_link.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MessageLink {
+    /// The Id of the guild the message was sent in, or `None` if the message
+    /// was sent in a private channel.
+    pub guild_id: Option<u64>,
+    /// The Id of the channel the message was sent in.
+    pub channel_id: u64,
+    /// The Id of the message.
+    pub message_id: u64,
+}
+
+/// Retrieves the guild, channel, and message Ids out of a link to a message.
+///
+/// Links from the stable, PTB, and Canary clients are supported, on both the
+/// `discord.com` and `discordapp.com` domains. The scheme may be omitted.
+///
+/// If the link is invalid, then `None` is returned.
+///
+/// # Examples
+///
+/// Retrieving the Ids from a link to a message in a guild:
+///
+/// ```rust
+/// use serenity_utils::{MessageLink, parse_message_link};
+///
+/// let link = "https://discord.com/channels/381880193251409931/381880193700069377/423997218574385152";
+///
+/// assert_eq!(parse_message_link(link), Some(MessageLink {
+///     guild_id: Some(381880193251409931),
+///     channel_id: 381880193700069377,
+///     message_id: 423997218574385152,
+/// }));
+/// ```
+///
+/// Links to messages in private channels have no guild Id:
+///
+/// ```rust
+/// use serenity_utils::parse_message_link;
+///
+/// let link = "https://canary.discordapp.com/channels/@me/381880193700069377/423997218574385152";
+///
+/// assert_eq!(parse_message_link(link).unwrap().guild_id, None);
+/// ```
+///
+/// Asserting that a link to something other than a message returns `None`:
+///
+/// ```rust
+/// use serenity_utils::parse_message_link;
+///
+/// assert!(parse_message_link("https://discord.com/channels/381880193251409931").is_none());
+/// assert!(parse_message_link("https://example.com/channels/1/2/3").is_none());
+/// ```
+pub fn parse_message_link(link: &str) -> Option<MessageLink> {
+    let path = strip_host(link, CLIENT_HOSTS)?.strip_prefix("/channels/")?;
+    let mut parts = path.split('/');
+
+    let guild_id = match parts.next()? {
+        "@me" => None,
+        id => Some(parse_id(id).ok()?),
+    };
+    let channel_id = parse_id(parts.next()?).ok()?;
+    let message_id = parse_id(parts.next()?).ok()?;
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some(MessageLink {
+        guild_id,
+        channel_id,
+        message_id,
+    })
+}
+
+/// Strips the optional scheme and the host from the start of an invite URL,
+/// returning the remainder starting at the code.
+fn strip_invite_host(url: &str) -> Option<&str> {
+    if let Some(path) = strip_host(url, &["discord.gg"]) {
+        return path.strip_prefix('/');
+    }
+
+    strip_host(url, CLIENT_HOSTS)?.strip_prefix("/invite/")
+}
+
+/// Reads an invite code, an optional trailing slash, and an optional query
+/// string from the start of the input.
+///
+/// Returns the invite along with the number of bytes read, or `None` if there
+/// is no code.
+fn read_invite<'a>(input: &'a str) -> Option<(InviteLink<'a>, usize)> {
+    let code_len = input
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+        .unwrap_or(input.len());
+
+    if code_len == 0 {
+        return None;
+    }
+
+    let mut len = code_len;
+
+    if input[len..].starts_with('/') {
+        len += 1;
+    }
+
+    let mut event_id = None;
+
+    if input[len..].starts_with('?') {
+        let query = &input[len + 1..];
+        let query_len = query
+            .find(|c: char| !c.is_ascii_alphanumeric() && !"=&-_.%".contains(c))
+            .unwrap_or(query.len());
+
+        for pair in query[..query_len].split('&') {
+            // The first valid Id is kept, so that a later one cannot hide it.
+            if let (None, Some(id)) = (event_id, pair.strip_prefix("event=")) {
+                event_id = parse_id(id).ok();
+            }
+        }
+
+        len += 1 + query_len;
+    }
+
+    let invite = InviteLink {
+        code: &input[..code_len],
+        event_id,
+    };
+
+    Some((invite, len))
+}
+
+/// Strips the optional scheme, an optional `www.` subdomain, and one of the
+/// given hosts from the start of a URL, returning the remaining path.
+///
+/// Hosts are matched case-insensitively, and must be followed by a `/`.
+fn strip_host<'a>(url: &'a str, hosts: &[&str]) -> Option<&'a str> {
+    let url = if starts_with_ignore_case(url, "https://") {
+        &url[8..]
+    } else if starts_with_ignore_case(url, "http://") {
+        &url[7..]
+    } else {
+        url
+    };
+    let url = if starts_with_ignore_case(url, "www.") { &url[4..] } else { url };
+
+    hosts.iter()
+        .find(|host| starts_with_ignore_case(url, host) && url[host.len()..].starts_with('/'))
+        .map(|host| &url[host.len()..])
+}
+
+fn ends_with_ignore_case(s: &str, suffix: &str) -> bool {
+    s.len() >= suffix.len() && s.as_bytes()[s.len() - suffix.len()..].eq_ignore_ascii_case(suffix.as_bytes())
+}
+
+fn find_ignore_case(s: &str, needle: &str) -> Option<usize> {
+    s.as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+    s.len() >= prefix.len() && s.as_bytes()[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+}
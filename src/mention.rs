@@ -0,0 +1,362 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::Range;
+use super::{parse_channel, parse_emoji, parse_role, parse_username};
+
+/// A parsed mention of a Discord entity, as found in message content.
+///
+/// Mentions are parsed via [`parse_mention`], which determines the kind of
+/// mention in a single pass.
+///
+/// [`parse_mention`]: fn.parse_mention.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Mention<'a> {
+    /// A user mention in the form of `<@id>`.
+    User(u64),
+    /// A user mention in the nickname form of `<@!id>`.
+    Nickname(u64),
+    /// A role mention in the form of `<@&id>`.
+    Role(u64),
+    /// A channel mention in the form of `<#id>`.
+    Channel(u64),
+    /// A custom emoji in the form of `<:name:id>`.
+    Emoji(&'a str, u64),
+    /// An animated custom emoji in the form of `<a:name:id>`.
+    AnimatedEmoji(&'a str, u64),
+    /// The `@everyone` mention.
+    Everyone,
+    /// The `@here` mention.
+    Here,
+}
+
+impl<'a> Mention<'a> {
+    /// Returns the Id of the mentioned entity.
+    ///
+    /// `None` is returned for [`Everyone`] and [`Here`], as they do not refer
+    /// to an entity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Mention;
+    ///
+    /// assert_eq!(Mention::Role(136107769680887808).id(), Some(136107769680887808));
+    /// assert_eq!(Mention::Everyone.id(), None);
+    /// ```
+    ///
+    /// [`Everyone`]: #variant.Everyone
+    /// [`Here`]: #variant.Here
+    pub fn id(&self) -> Option<u64> {
+        match *self {
+            Mention::User(id) |
+            Mention::Nickname(id) |
+            Mention::Role(id) |
+            Mention::Channel(id) |
+            Mention::Emoji(_, id) |
+            Mention::AnimatedEmoji(_, id) => Some(id),
+            Mention::Everyone | Mention::Here => None,
+        }
+    }
+}
+
+impl<'a> Display for Mention<'a> {
+    /// Formats the mention as it would appear in message content.
+    ///
+    /// This is the inverse of [`parse_mention`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Mention;
+    ///
+    /// assert_eq!(Mention::Nickname(114941315417899012).to_string(), "<@!114941315417899012>");
+    /// assert_eq!(Mention::AnimatedEmoji("blobDance", 302516740095606785).to_string(),
+    ///            "<a:blobDance:302516740095606785>");
+    /// ```
+    ///
+    /// [`parse_mention`]: fn.parse_mention.html
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            Mention::User(id) => write!(f, "<@{}>", id),
+            Mention::Nickname(id) => write!(f, "<@!{}>", id),
+            Mention::Role(id) => write!(f, "<@&{}>", id),
+            Mention::Channel(id) => write!(f, "<#{}>", id),
+            Mention::Emoji(name, id) => write!(f, "<:{}:{}>", name, id),
+            Mention::AnimatedEmoji(name, id) => write!(f, "<a:{}:{}>", name, id),
+            Mention::Everyone => f.write_str("@everyone"),
+            Mention::Here => f.write_str("@here"),
+        }
+    }
+}
+
+/// A custom emoji parsed from an emoji usage via [`parse_emoji`].
+///
+/// [`parse_emoji`]: fn.parse_emoji.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct EmojiRef<'a> {
+    /// Whether the emoji is animated.
+    pub animated: bool,
+    /// The name of the emoji.
+    pub name: &'a str,
+    /// The Id of the emoji.
+    pub id: u64,
+}
+
+impl<'a> EmojiRef<'a> {
+    /// Generates a URL to the emoji's image on Discord's CDN.
+    ///
+    /// Animated emojis point to a `.gif`, while static emojis point to a
+    /// `.png`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::parse_emoji;
+    ///
+    /// let emoji = parse_emoji("<a:blobDance:302516740095606785>").unwrap();
+    ///
+    /// assert_eq!(emoji.url(), "https://cdn.discordapp.com/emojis/302516740095606785.gif");
+    /// ```
+    pub fn url(&self) -> String {
+        let ext = if self.animated { "gif" } else { "png" };
+
+        format!("https://cdn.discordapp.com/emojis/{}.{}", self.id, ext)
+    }
+}
+
+impl<'a> Display for EmojiRef<'a> {
+    /// Formats the emoji as it would appear in message content.
+    ///
+    /// This is the inverse of [`parse_emoji`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::EmojiRef;
+    ///
+    /// let emoji = EmojiRef {
+    ///     animated: false,
+    ///     name: "smugAnimeFace",
+    ///     id: 302516740095606785,
+    /// };
+    ///
+    /// assert_eq!(emoji.to_string(), "<:smugAnimeFace:302516740095606785>");
+    /// ```
+    ///
+    /// [`parse_emoji`]: fn.parse_emoji.html
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let prefix = if self.animated { "a" } else { "" };
+
+        write!(f, "<{}:{}:{}>", prefix, self.name, self.id)
+    }
+}
+
+/// A mention of an application command, which can be clicked on to start
+/// using the command.
+///
+/// This is created via [`parse_command`]. Formatting this via `Display`
+/// produces the mention in the form of `</name subcommand_group subcommand:id>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::CommandMention;
+///
+/// let command = CommandMention {
+///     name: "ban",
+///     subcommand_group: None,
+///     subcommand: Some("user"),
+///     id: 1009175624553680926,
+/// };
+///
+/// assert_eq!(command.to_string(), "</ban user:1009175624553680926>");
+/// ```
+///
+/// [`parse_command`]: fn.parse_command.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CommandMention<'a> {
+    /// The name of the command.
+    pub name: &'a str,
+    /// The name of the subcommand group, if any.
+    pub subcommand_group: Option<&'a str>,
+    /// The name of the subcommand, if any.
+    pub subcommand: Option<&'a str>,
+    /// The Id of the command.
+    pub id: u64,
+}
+
+impl<'a> Display for CommandMention<'a> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "</{}", self.name)?;
+
+        if let Some(group) = self.subcommand_group {
+            write!(f, " {}", group)?;
+        }
+
+        if let Some(subcommand) = self.subcommand {
+            write!(f, " {}", subcommand)?;
+        }
+
+        write!(f, ":{}>", self.id)
+    }
+}
+
+/// An iterator over every mention embedded in a piece of text.
+///
+/// This is created via [`mentions`].
+///
+/// [`mentions`]: fn.mentions.html
+#[derive(Clone, Debug)]
+pub struct Mentions<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Iterator for Mentions<'a> {
+    type Item = (Mention<'a>, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(offset) = self.text[self.pos..].find(&['<', '@'][..]) {
+            let start = self.pos + offset;
+            let rest = &self.text[start..];
+
+            let end = if rest.starts_with('@') {
+                if rest.starts_with("@everyone") {
+                    Some(start + 9)
+                } else if rest.starts_with("@here") {
+                    Some(start + 5)
+                } else {
+                    None
+                }
+            } else {
+                rest.find('>').map(|pos| start + pos + 1)
+            };
+
+            if let Some(end) = end {
+                if let Some(mention) = parse_mention(&self.text[start..end]) {
+                    self.pos = end;
+
+                    return Some((mention, start..end));
+                }
+            }
+
+            self.pos = start + 1;
+        }
+
+        self.pos = self.text.len();
+
+        None
+    }
+}
+
+/// Finds every mention embedded anywhere within a piece of text, such as the
+/// content of a message.
+///
+/// Each mention is yielded alongside its byte range within the text.
+///
+/// # Examples
+///
+/// Counting the users mentioned in a message:
+///
+/// ```rust
+/// use serenity_utils::{Mention, mentions};
+///
+/// let content = "hey <@114941315417899012> and <@!81384788765712384>, see <#81384788765712384>";
+///
+/// let users = mentions(content)
+///     .filter(|&(mention, _)| match mention {
+///         Mention::User(_) | Mention::Nickname(_) => true,
+///         _ => false,
+///     })
+///     .count();
+///
+/// assert_eq!(users, 2);
+/// ```
+///
+/// Retrieving the span of a mention:
+///
+/// ```rust
+/// use serenity_utils::{Mention, mentions};
+///
+/// let content = "ping <@&136107769680887808>!";
+/// let (mention, span) = mentions(content).next().unwrap();
+///
+/// assert_eq!(mention, Mention::Role(136107769680887808));
+/// assert_eq!(&content[span], "<@&136107769680887808>");
+/// ```
+pub fn mentions<'a>(text: &'a str) -> Mentions<'a> {
+    Mentions {
+        text,
+        pos: 0,
+    }
+}
+
+/// Parses a mention of any kind, determining which kind of mention it is.
+///
+/// This is a convenience over calling [`parse_username`], [`parse_role`],
+/// [`parse_channel`], and [`parse_emoji`] one after another.
+///
+/// If the input is not exactly one valid mention, then `None` is returned.
+///
+/// # Examples
+///
+/// Matching on the kind of a mention:
+///
+/// ```rust
+/// use serenity_utils::{Mention, parse_mention};
+///
+/// assert_eq!(parse_mention("<@114941315417899012>"), Some(Mention::User(114941315417899012)));
+/// assert_eq!(parse_mention("<@!114941315417899012>"), Some(Mention::Nickname(114941315417899012)));
+/// assert_eq!(parse_mention("<@&136107769680887808>"), Some(Mention::Role(136107769680887808)));
+/// assert_eq!(parse_mention("<#81384788765712384>"), Some(Mention::Channel(81384788765712384)));
+/// assert_eq!(parse_mention("@everyone"), Some(Mention::Everyone));
+/// ```
+///
+/// Custom emojis retain their name:
+///
+/// ```rust
+/// use serenity_utils::{Mention, parse_mention};
+///
+/// let expected = Mention::AnimatedEmoji("blobDance", 302516740095606785);
+///
+/// assert_eq!(parse_mention("<a:blobDance:302516740095606785>"), Some(expected));
+/// ```
+///
+/// Asserting that text which is not a mention returns `None`:
+///
+/// ```rust
+/// use serenity_utils::parse_mention;
+///
+/// assert!(parse_mention("hello").is_none());
+/// assert!(parse_mention("<@&136107769680887808").is_none());
+/// ```
+///
+/// [`parse_channel`]: fn.parse_channel.html
+/// [`parse_emoji`]: fn.parse_emoji.html
+/// [`parse_role`]: fn.parse_role.html
+/// [`parse_username`]: fn.parse_username.html
+pub fn parse_mention<'a>(mention: &'a str) -> Option<Mention<'a>> {
+    match mention {
+        "@everyone" => return Some(Mention::Everyone),
+        "@here" => return Some(Mention::Here),
+        _ => {},
+    }
+
+    if mention.starts_with("<@&") {
+        parse_role(mention).map(Mention::Role)
+    } else if mention.starts_with("<@!") {
+        parse_username(mention).map(Mention::Nickname)
+    } else if mention.starts_with("<@") {
+        parse_username(mention).map(Mention::User)
+    } else if mention.starts_with("<#") {
+        parse_channel(mention).map(Mention::Channel)
+    } else if mention.starts_with("<:") || mention.starts_with("<a:") {
+        parse_emoji(mention).map(|emoji| if emoji.animated {
+            Mention::AnimatedEmoji(emoji.name, emoji.id)
+        } else {
+            Mention::Emoji(emoji.name, emoji.id)
+        })
+    } else {
+        None
+    }
+}
+
@@ -0,0 +1,286 @@
+use std::borrow::Cow;
+use std::iter::Peekable;
+use super::args::{FromArg, QUOTES_TOKENIZER, Token};
+use super::error::{ParseError, Result};
+
+/// A parser which separates the flags and options of a command from its
+/// positional arguments.
+///
+/// Arguments are split in the same way as [`parse_quotes`], and then the
+/// following forms are recognised:
+///
+/// - `--name` and `-n`, for flags and options;
+/// - `--name=value`, `--name value`, `-n value` and `-nvalue`, for options;
+/// - `-abc`, for several short flags at once;
+/// - `name=value` and `name:value`, for registered names only.
+///
+/// An argument of `--` ends parsing, and everything after it is positional.
+/// Negative numbers such as `-5` are positional rather than short flags.
+///
+/// A registered flag or option is never taken as the value of the option
+/// before it, so a value which looks like one must be given inline, such
+/// as `--reason=--silent`.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::OptionParser;
+///
+/// let parser = OptionParser::new()
+///     .option("reason", Some('r'))
+///     .option("days", None)
+///     .flag("silent", Some('s'));
+///
+/// let options = parser.parse(r#"<@114941315417899012> --reason "spam" days=7 -s"#).unwrap();
+///
+/// assert_eq!(options.positional()[0].value, "<@114941315417899012>");
+/// assert_eq!(options.value("reason"), Some("spam"));
+/// assert_eq!(options.get::<u64>("days").unwrap(), Some(7));
+/// assert!(options.has("silent"));
+/// ```
+///
+/// [`parse_quotes`]: fn.parse_quotes.html
+#[derive(Clone, Debug, Default)]
+pub struct OptionParser {
+    specs: Vec<OptionSpec>,
+}
+
+#[derive(Clone, Debug)]
+struct OptionSpec {
+    name: &'static str,
+    short: Option<char>,
+    takes_value: bool,
+}
+
+impl OptionParser {
+    /// Creates a parser with no flags or options registered.
+    pub fn new() -> OptionParser { OptionParser::default() }
+
+    /// Registers a flag, which takes no value, with an optional short name.
+    pub fn flag(mut self, name: &'static str, short: Option<char>) -> Self {
+        self.specs.push(OptionSpec { name, short, takes_value: false });
+
+        self
+    }
+
+    /// Registers an option, which takes a value, with an optional short name.
+    pub fn option(mut self, name: &'static str, short: Option<char>) -> Self {
+        self.specs.push(OptionSpec { name, short, takes_value: true });
+
+        self
+    }
+
+    /// Parses the flags, options and positional arguments of a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::UnknownOption`] if a flag or option is given that
+    /// has not been registered, [`ParseError::MissingOptionValue`] if an option
+    /// is given without a value or is followed by another flag or option, or
+    /// [`ParseError::UnexpectedOptionValue`] if a flag is given a value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{Error, OptionParser, ParseError};
+    ///
+    /// let parser = OptionParser::new().flag("silent", Some('s'));
+    ///
+    /// match parser.parse("ban --loud") {
+    ///     Err(Error::Parse(ParseError::UnknownOption(offset))) => assert_eq!(offset, 4),
+    ///     _ => unreachable!(),
+    /// }
+    ///
+    /// match parser.parse("ban --silent=no") {
+    ///     Err(Error::Parse(ParseError::UnexpectedOptionValue(name))) => assert_eq!(name, "silent"),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    ///
+    /// [`ParseError::MissingOptionValue`]: enum.ParseError.html#variant.MissingOptionValue
+    /// [`ParseError::UnexpectedOptionValue`]: enum.ParseError.html#variant.UnexpectedOptionValue
+    /// [`ParseError::UnknownOption`]: enum.ParseError.html#variant.UnknownOption
+    pub fn parse<'a>(&self, message: &'a str) -> Result<Options<'a>> {
+        let mut tokens = QUOTES_TOKENIZER.tokens(message).peekable();
+        let mut options = Options {
+            positional: Vec::new(),
+            values: Vec::new(),
+        };
+
+        while let Some(token) = tokens.next() {
+            if token.value == "--" {
+                options.positional.extend(tokens);
+
+                break;
+            }
+
+            if let Some(long) = token.value.strip_prefix("--") {
+                let (name, value) = match long.find('=') {
+                    Some(idx) => (&long[..idx], Some(&long[idx + 1..])),
+                    None => (long, None),
+                };
+                let spec = self.find(|spec| spec.name == name)
+                    .ok_or(ParseError::UnknownOption(token.offset))?;
+                let value = match value {
+                    Some(_) if !spec.takes_value => {
+                        return Err(ParseError::UnexpectedOptionValue(spec.name).into());
+                    },
+                    Some(value) => Some(substr(&token.value, value)),
+                    None if spec.takes_value => Some(self.next_value(&mut tokens, spec)?),
+                    None => None,
+                };
+
+                options.values.push((spec.name, value));
+            } else if is_short(&token.value) {
+                let shorts = &token.value[1..];
+
+                for (idx, short) in shorts.char_indices() {
+                    let spec = self.find(|spec| spec.short == Some(short))
+                        .ok_or(ParseError::UnknownOption(token.offset))?;
+
+                    if !spec.takes_value {
+                        options.values.push((spec.name, None));
+
+                        continue;
+                    }
+
+                    let rest = &shorts[idx + short.len_utf8()..];
+                    let value = if rest.is_empty() {
+                        self.next_value(&mut tokens, spec)?
+                    } else {
+                        substr(&token.value, rest)
+                    };
+
+                    options.values.push((spec.name, Some(value)));
+
+                    break;
+                }
+            } else if let Some((spec, value)) = self.find_pair(&token.value) {
+                if !spec.takes_value {
+                    return Err(ParseError::UnexpectedOptionValue(spec.name).into());
+                }
+
+                let value = substr(&token.value, value);
+
+                options.values.push((spec.name, Some(value)));
+            } else {
+                options.positional.push(token);
+            }
+        }
+
+        Ok(options)
+    }
+
+    fn find<F: Fn(&OptionSpec) -> bool>(&self, f: F) -> Option<&OptionSpec> {
+        self.specs.iter().find(|spec| f(spec))
+    }
+
+    /// Returns whether an argument is `--` or starts with a registered flag or
+    /// option, in its long or short form.
+    fn is_option(&self, arg: &str) -> bool {
+        if let Some(long) = arg.strip_prefix("--") {
+            let name = long.split('=').next().unwrap_or(long);
+
+            return long.is_empty() || self.find(|spec| spec.name == name).is_some();
+        }
+
+        is_short(arg) && arg[1..]
+            .chars()
+            .next()
+            .is_some_and(|short| self.find(|spec| spec.short == Some(short)).is_some())
+    }
+
+    /// Takes the next argument as the value of an option, unless it is itself
+    /// a flag or option.
+    fn next_value<'a, I>(&self, tokens: &mut Peekable<I>, spec: &OptionSpec) -> Result<Cow<'a, str>>
+        where I: Iterator<Item = Token<'a>> {
+        tokens
+            .next_if(|token| !self.is_option(&token.value))
+            .map(|token| token.value)
+            .ok_or_else(|| ParseError::MissingOptionValue(spec.name).into())
+    }
+
+    /// Finds the registered name of a `name=value` or `name:value` pair,
+    /// returning the value.
+    fn find_pair<'b>(&self, arg: &'b str) -> Option<(&OptionSpec, &'b str)> {
+        let idx = arg.find(['=', ':'])?;
+        let spec = self.find(|spec| spec.name == &arg[..idx])?;
+
+        Some((spec, &arg[idx + 1..]))
+    }
+}
+
+/// The flags, options and positional arguments parsed by an
+/// [`OptionParser`].
+///
+/// [`OptionParser`]: struct.OptionParser.html
+#[derive(Clone, Debug)]
+pub struct Options<'a> {
+    positional: Vec<Token<'a>>,
+    values: Vec<(&'static str, Option<Cow<'a, str>>)>,
+}
+
+impl<'a> Options<'a> {
+    /// Returns the arguments which were not flags or options, in order.
+    pub fn positional(&self) -> &[Token<'a>] { &self.positional }
+
+    /// Returns whether a flag or option was given, by its long name.
+    pub fn has(&self, name: &str) -> bool {
+        self.values.iter().any(|&(n, _)| n == name)
+    }
+
+    /// Returns the value of an option, by its long name.
+    ///
+    /// If the option was given more than once, the last value is returned.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .rev()
+            .find(|(n, value)| *n == name && value.is_some())
+            .and_then(|(_, value)| value.as_ref().map(|value| &value[..]))
+    }
+
+    /// Returns every value given for an option, by its long name, in order.
+    pub fn values<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b str> + 'b {
+        self.values
+            .iter()
+            .filter(move |&&(n, _)| n == name)
+            .filter_map(|(_, value)| value.as_ref().map(|value| &value[..]))
+    }
+
+    /// Parses the value of an option, by its long name.
+    ///
+    /// Returns `Ok(None)` if the option was not given.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from parsing the value.
+    pub fn get<T: FromArg>(&self, name: &str) -> Result<Option<T>> {
+        match self.value(name) {
+            Some(value) => T::from_arg(value).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Returns whether an argument is one or more short flags, rather than a
+/// negative number or a lone `-`.
+fn is_short(arg: &str) -> bool {
+    match arg.strip_prefix('-') {
+        Some(rest) => rest.chars().next().is_some_and(|c| !c.is_ascii_digit() && c != '.'),
+        None => false,
+    }
+}
+
+/// Takes a part of an argument, borrowing it from the message if the argument
+/// was itself borrowed.
+fn substr<'a>(arg: &Cow<'a, str>, part: &str) -> Cow<'a, str> {
+    match *arg {
+        Cow::Borrowed(arg) => {
+            let start = part.as_ptr() as usize - arg.as_ptr() as usize;
+
+            Cow::Borrowed(&arg[start..start + part.len()])
+        },
+        Cow::Owned(_) => Cow::Owned(part.to_owned()),
+    }
+}
@@ -0,0 +1,250 @@
+use super::colour::Colour;
+
+#[cfg(feature = "image")]
+use std::cmp::Reverse;
+
+/// The colour space that a [`gradient`] is interpolated in.
+///
+/// [`gradient`]: fn.gradient.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Interpolation {
+    /// Interpolates each RGB component separately.
+    ///
+    /// This is what most image editors do, but can pass through muddy or
+    /// unexpectedly dark colours.
+    Rgb,
+    /// Interpolates in the OKLab colour space, so that each step looks evenly
+    /// spaced.
+    Oklab,
+}
+
+impl Default for Interpolation {
+    /// Creates a default value for an `Interpolation`, which is
+    /// [`Interpolation::Oklab`].
+    ///
+    /// [`Interpolation::Oklab`]: #variant.Oklab
+    fn default() -> Interpolation { Interpolation::Oklab }
+}
+
+/// Generates a gradient of evenly spaced colours passing through each of the
+/// given stops, in order.
+///
+/// The first and last colours of the gradient are the first and last stops,
+/// and any stops between them are spaced evenly along the gradient. No colours
+/// are returned if there are no stops.
+///
+/// # Examples
+///
+/// Colour the embeds of a leaderboard from gold to red:
+///
+/// ```rust
+/// use serenity_utils::{Colour, Interpolation, gradient};
+///
+/// let colours = gradient(&[Colour::gold(), Colour::red()], 5, Interpolation::Oklab);
+///
+/// assert_eq!(colours.len(), 5);
+/// assert_eq!(colours[0], Colour::gold());
+/// assert_eq!(colours[4], Colour::red());
+/// ```
+///
+/// Interpolating in RGB between black and white gives a lighter middle grey
+/// than interpolating in OKLab:
+///
+/// ```rust
+/// use serenity_utils::{Colour, Interpolation, gradient};
+///
+/// let stops = [Colour::new(0x000000), Colour::new(0xFFFFFF)];
+///
+/// assert_eq!(gradient(&stops, 3, Interpolation::Rgb)[1], Colour::new(0x808080));
+/// assert_eq!(gradient(&stops, 3, Interpolation::Oklab)[1], Colour::new(0x636363));
+/// ```
+pub fn gradient(stops: &[Colour], count: usize, interpolation: Interpolation) -> Vec<Colour> {
+    let (first, last) = match (stops.first(), stops.last()) {
+        (Some(&first), Some(&last)) => (first, last),
+        _ => return Vec::new(),
+    };
+
+    if count == 1 || stops.len() == 1 {
+        return vec![first; count];
+    }
+
+    let segments = (stops.len() - 1) as f64;
+
+    (0..count)
+        .map(|idx| {
+            if idx == count - 1 {
+                return last;
+            }
+
+            let position = idx as f64 / (count - 1) as f64 * segments;
+            let segment = position.floor() as usize;
+            let t = position - segment as f64;
+
+            interpolate(stops[segment], stops[segment + 1], t, interpolation)
+        })
+        .collect()
+}
+
+/// Generates a palette of visually distinct colours with evenly spaced hues.
+///
+/// Every colour has the same perceived lightness and colourfulness, so that
+/// none stand out more than the others, such as when colouring roles.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::hue_palette;
+///
+/// let colours = hue_palette(6);
+///
+/// assert_eq!(colours.len(), 6);
+///
+/// for (idx, colour) in colours.iter().enumerate() {
+///     assert!(!colours[idx + 1..].contains(colour));
+/// }
+/// ```
+pub fn hue_palette(count: usize) -> Vec<Colour> {
+    const LIGHTNESS: f64 = 0.7;
+    const CHROMA: f64 = 0.12;
+    const START: f64 = 30.0;
+
+    (0..count)
+        .map(|idx| {
+            let hue = (START + 360.0 * idx as f64 / count as f64).to_radians();
+
+            Colour::from_oklab(LIGHTNESS, CHROMA * hue.cos(), CHROMA * hue.sin())
+        })
+        .collect()
+}
+
+/// Interpolates between two colours, where a `t` of `0.0` is the first.
+fn interpolate(a: Colour, b: Colour, t: f64, interpolation: Interpolation) -> Colour {
+    match interpolation {
+        Interpolation::Rgb => a.mix(b, t as f32),
+        Interpolation::Oklab => {
+            let (al, aa, ab) = a.to_oklab();
+            let (bl, ba, bb) = b.to_oklab();
+
+            Colour::from_oklab(
+                al + (bl - al) * t,
+                aa + (ba - aa) * t,
+                ab + (bb - ab) * t,
+            )
+        },
+    }
+}
+
+/// Clusters colours into up to `count` groups with k-means in OKLab,
+/// returning the centre of each group from largest to smallest.
+///
+/// The first centre is the mean of every colour, and each following centre is
+/// the colour furthest from those already chosen, so that the result does not
+/// depend on chance.
+#[cfg(feature = "image")]
+pub(crate) fn kmeans(colours: &[Colour], count: usize) -> Vec<Colour> {
+    const ITERATIONS: usize = 20;
+
+    let points = colours.iter().map(Colour::to_oklab).collect::<Vec<_>>();
+
+    let mut centres = match mean(points.iter()) {
+        Some(centre) if count > 0 => vec![centre],
+        _ => return Vec::new(),
+    };
+
+    while centres.len() < count {
+        let furthest = points
+            .iter()
+            .map(|&point| (point, nearest(&centres, point).1))
+            .fold(None, |best: Option<((f64, f64, f64), f64)>, candidate| match best {
+                Some(best) if best.1 >= candidate.1 => Some(best),
+                _ => Some(candidate),
+            });
+
+        match furthest {
+            Some((point, distance)) if distance > 0.0 => centres.push(point),
+            _ => break,
+        }
+    }
+
+    let mut assignments = vec![0; points.len()];
+
+    for _ in 0..ITERATIONS {
+        let mut changed = false;
+
+        for (assignment, &point) in assignments.iter_mut().zip(&points) {
+            let idx = nearest(&centres, point).0;
+
+            changed |= *assignment != idx;
+            *assignment = idx;
+        }
+
+        for (idx, centre) in centres.iter_mut().enumerate() {
+            let members = points
+                .iter()
+                .zip(&assignments)
+                .filter(|&(_, &assignment)| assignment == idx)
+                .map(|(point, _)| point);
+
+            if let Some(mean) = mean(members) {
+                *centre = mean;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    let mut sizes = centres
+        .iter()
+        .enumerate()
+        .map(|(idx, &centre)| (assignments.iter().filter(|&&a| a == idx).count(), centre))
+        .filter(|&(size, _)| size > 0)
+        .collect::<Vec<_>>();
+
+    // A stable sort keeps clusters of equal size in the order they were found.
+    sizes.sort_by_key(|&(size, _)| Reverse(size));
+
+    let mut palette = Vec::with_capacity(sizes.len());
+
+    for (_, (l, a, b)) in sizes {
+        let colour = Colour::from_oklab(l, a, b);
+
+        if !palette.contains(&colour) {
+            palette.push(colour);
+        }
+    }
+
+    palette
+}
+
+/// Returns the index of and squared distance to the centre nearest a point.
+#[cfg(feature = "image")]
+fn nearest(centres: &[(f64, f64, f64)], point: (f64, f64, f64)) -> (usize, f64) {
+    centres
+        .iter()
+        .map(|centre| {
+            (centre.0 - point.0).powi(2) + (centre.1 - point.1).powi(2) + (centre.2 - point.2).powi(2)
+        })
+        .enumerate()
+        .fold((0, f64::INFINITY), |best, (idx, distance)| {
+            if distance < best.1 { (idx, distance) } else { best }
+        })
+}
+
+/// Returns the mean of a set of points, if there are any.
+#[cfg(feature = "image")]
+fn mean<'a, I>(points: I) -> Option<(f64, f64, f64)>
+    where I: Iterator<Item = &'a (f64, f64, f64)> {
+    let (sum, count) = points.fold(((0.0, 0.0, 0.0), 0usize), |(sum, count), point| {
+        ((sum.0 + point.0, sum.1 + point.1, sum.2 + point.2), count + 1)
+    });
+
+    if count == 0 {
+        return None;
+    }
+
+    let count = count as f64;
+
+    Some((sum.0 / count, sum.1 / count, sum.2 / count))
+}
@@ -0,0 +1,298 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use super::error::Error;
+use super::parse_u64;
+
+#[cfg(feature = "serde")]
+use serde::de::{Deserialize, Deserializer, Error as DeError, Unexpected, Visitor};
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, Serializer};
+
+/// The Discord epoch, the first millisecond of 2015, in milliseconds since the
+/// Unix epoch.
+///
+/// Timestamps within [`Snowflake`]s are relative to this.
+///
+/// [`Snowflake`]: struct.Snowflake.html
+pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// The largest timestamp that fits within the 42 bits of a Snowflake.
+const MAX_TIMESTAMP: u64 = (1 << 42) - 1;
+
+/// A utility struct for working with the unique Ids that Discord assigns to
+/// entities, known as snowflakes.
+///
+/// A snowflake is a 64-bit integer made up of, from most significant bit to
+/// least:
+///
+/// - 42 bits of milliseconds since the [`DISCORD_EPOCH`];
+/// - 5 bits of internal worker Id;
+/// - 5 bits of internal process Id;
+/// - 12 bits of increment.
+///
+/// The Ids returned from functions such as [`parse_username`] can be converted
+/// into a `Snowflake` via `From`.
+///
+/// # Examples
+///
+/// Retrieve when a user was created from their mention:
+///
+/// ```rust
+/// use serenity_utils::{Snowflake, parse_username};
+///
+/// let id = parse_username("<@175928847299117063>").unwrap();
+/// let snowflake = Snowflake::from(id);
+///
+/// assert_eq!(snowflake.timestamp(), 41944705796);
+/// assert_eq!(snowflake.worker_id(), 1);
+/// assert_eq!(snowflake.process_id(), 0);
+/// assert_eq!(snowflake.increment(), 7);
+/// ```
+///
+/// [`DISCORD_EPOCH`]: constant.DISCORD_EPOCH.html
+/// [`parse_username`]: fn.parse_username.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Snowflake(pub u64);
+
+impl Snowflake {
+    /// Generates a new Snowflake with the given integer value set.
+    #[inline]
+    pub fn new(value: u64) -> Snowflake { Snowflake(value) }
+
+    /// Generates the smallest Snowflake that could have been created at the
+    /// given time.
+    ///
+    /// This is useful as a lower bound when paginating through entities by
+    /// their creation time. Times before the [`DISCORD_EPOCH`] produce a
+    /// Snowflake of `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Snowflake;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let time = UNIX_EPOCH + Duration::from_millis(1462015105796);
+    /// let snowflake = Snowflake::from_timestamp(time);
+    ///
+    /// assert_eq!(snowflake.0, 175928847298985984);
+    /// assert_eq!(snowflake.created_at(), time);
+    /// ```
+    ///
+    /// [`DISCORD_EPOCH`]: constant.DISCORD_EPOCH.html
+    pub fn from_timestamp(time: SystemTime) -> Snowflake {
+        Snowflake::from_unix_millis(system_time_millis(time))
+    }
+
+    /// Generates the largest Snowflake that could have been created at the
+    /// given time.
+    ///
+    /// This is useful as an upper bound when paginating through entities by
+    /// their creation time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Snowflake;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let time = UNIX_EPOCH + Duration::from_millis(1462015105796);
+    /// let snowflake = Snowflake::max_from_timestamp(time);
+    ///
+    /// assert_eq!(snowflake.0, 175928847303180287);
+    /// assert_eq!(snowflake.created_at(), time);
+    /// ```
+    pub fn max_from_timestamp(time: SystemTime) -> Snowflake {
+        Snowflake::max_from_unix_millis(system_time_millis(time))
+    }
+
+    /// Generates the smallest Snowflake that could have been created at the
+    /// given number of milliseconds since the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Snowflake;
+    ///
+    /// let snowflake = Snowflake::from_unix_millis(1462015105796);
+    ///
+    /// assert_eq!(snowflake.0, 175928847298985984);
+    /// assert_eq!(snowflake.unix_millis(), 1462015105796);
+    /// ```
+    pub fn from_unix_millis(millis: u64) -> Snowflake {
+        let timestamp = millis.saturating_sub(DISCORD_EPOCH).min(MAX_TIMESTAMP);
+
+        Snowflake(timestamp << 22)
+    }
+
+    /// Generates the largest Snowflake that could have been created at the
+    /// given number of milliseconds since the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Snowflake;
+    ///
+    /// let snowflake = Snowflake::max_from_unix_millis(1462015105796);
+    ///
+    /// assert_eq!(snowflake.0, 175928847303180287);
+    /// assert_eq!(snowflake.unix_millis(), 1462015105796);
+    /// ```
+    pub fn max_from_unix_millis(millis: u64) -> Snowflake {
+        Snowflake(Snowflake::from_unix_millis(millis).0 | 0x3F_FFFF)
+    }
+
+    /// Generates exclusive bounds covering every Snowflake created between two
+    /// times, inclusive of both.
+    ///
+    /// The first Snowflake of the returned pair is suitable for an `after`
+    /// query parameter, and the second for a `before` query parameter, such as
+    /// when retrieving the messages of a channel sent between two times.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Snowflake;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let start = UNIX_EPOCH + Duration::from_millis(1462015105796);
+    /// let end = start + Duration::from_secs(60 * 60);
+    /// let (after, before) = Snowflake::between(start, end);
+    ///
+    /// let message = Snowflake(175928847299117063);
+    ///
+    /// assert!(after < message && message < before);
+    /// ```
+    pub fn between(start: SystemTime, end: SystemTime) -> (Snowflake, Snowflake) {
+        let after = Snowflake::from_timestamp(start).0.saturating_sub(1);
+        let before = Snowflake::max_from_timestamp(end).0.saturating_add(1);
+
+        (Snowflake(after), Snowflake(before))
+    }
+
+    /// Returns the number of milliseconds since the [`DISCORD_EPOCH`] at which
+    /// this Snowflake was created.
+    ///
+    /// [`DISCORD_EPOCH`]: constant.DISCORD_EPOCH.html
+    pub fn timestamp(&self) -> u64 { self.0 >> 22 }
+
+    /// Returns the time at which this Snowflake was created.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Snowflake;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let created_at = Snowflake(175928847299117063).created_at();
+    ///
+    /// assert_eq!(created_at, UNIX_EPOCH + Duration::from_millis(1462015105796));
+    /// ```
+    pub fn created_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.unix_millis())
+    }
+
+    /// Returns the number of milliseconds since the Unix epoch at which this
+    /// Snowflake was created.
+    pub fn unix_millis(&self) -> u64 { DISCORD_EPOCH + self.timestamp() }
+
+    /// Returns the internal worker Id that generated this Snowflake.
+    pub fn worker_id(&self) -> u8 { ((self.0 >> 17) & 0x1F) as u8 }
+
+    /// Returns the internal process Id that generated this Snowflake.
+    pub fn process_id(&self) -> u8 { ((self.0 >> 12) & 0x1F) as u8 }
+
+    /// Returns the increment of this Snowflake, which is incremented for every
+    /// Id generated on its process.
+    pub fn increment(&self) -> u16 { (self.0 & 0xFFF) as u16 }
+}
+
+impl Display for Snowflake {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for Snowflake {
+    type Err = Error;
+
+    /// Parses a Snowflake from its decimal representation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Snowflake;
+    ///
+    /// let snowflake = "175928847299117063".parse::<Snowflake>().unwrap();
+    ///
+    /// assert_eq!(snowflake, Snowflake(175928847299117063));
+    /// assert!("abc".parse::<Snowflake>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_u64(s).map(Snowflake)
+    }
+}
+
+impl From<u64> for Snowflake {
+    fn from(value: u64) -> Snowflake { Snowflake(value) }
+}
+
+impl From<Snowflake> for u64 {
+    fn from(snowflake: Snowflake) -> u64 { snowflake.0 }
+}
+
+/// Converts a time into milliseconds since the Unix epoch, clamping times
+/// before the epoch to `0` and saturating times too far in the future.
+fn system_time_millis(time: SystemTime) -> u64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs()
+            .saturating_mul(1000)
+            .saturating_add(u64::from(duration.subsec_millis())),
+        Err(_) => 0,
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Snowflake {
+    /// Serializes the Snowflake as a string, as the Discord API does.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Snowflake {
+    /// Deserializes a Snowflake from either a string or an integer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(SnowflakeVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct SnowflakeVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> Visitor<'de> for SnowflakeVisitor {
+    type Value = Snowflake;
+
+    fn expecting(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str("a snowflake as a string or integer")
+    }
+
+    fn visit_i64<E: DeError>(self, value: i64) -> Result<Snowflake, E> {
+        if value < 0 {
+            return Err(E::invalid_value(Unexpected::Signed(value), &self));
+        }
+
+        Ok(Snowflake(value as u64))
+    }
+
+    fn visit_u64<E: DeError>(self, value: u64) -> Result<Snowflake, E> {
+        Ok(Snowflake(value))
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Snowflake, E> {
+        value.parse().map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+    }
+}
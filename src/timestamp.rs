@@ -0,0 +1,372 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// The style that the Discord client renders a [`Timestamp`] in.
+///
+/// The examples of each style are of the time `1618953630`, rendered in the
+/// `en-US` locale.
+///
+/// [`Timestamp`]: struct.Timestamp.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TimestampStyle {
+    /// A short time, such as `9:20 PM`.
+    ShortTime,
+    /// A long time, such as `9:20:30 PM`.
+    LongTime,
+    /// A short date, such as `04/20/2021`.
+    ShortDate,
+    /// A long date, such as `April 20, 2021`.
+    LongDate,
+    /// A short date and time, such as `April 20, 2021 9:20 PM`.
+    ///
+    /// This is the style used when none is specified.
+    ShortDateTime,
+    /// A long date and time, such as `Tuesday, April 20, 2021 9:20 PM`.
+    LongDateTime,
+    /// A time relative to the present, such as `2 months ago`.
+    RelativeTime,
+}
+
+impl TimestampStyle {
+    /// Returns the character which represents this style in timestamp markup.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::TimestampStyle;
+    ///
+    /// assert_eq!(TimestampStyle::RelativeTime.as_char(), 'R');
+    /// ```
+    pub fn as_char(&self) -> char {
+        use self::TimestampStyle::*;
+
+        match *self {
+            ShortTime => 't',
+            LongTime => 'T',
+            ShortDate => 'd',
+            LongDate => 'D',
+            ShortDateTime => 'f',
+            LongDateTime => 'F',
+            RelativeTime => 'R',
+        }
+    }
+
+    /// Retrieves the style represented by a character in timestamp markup.
+    ///
+    /// If the character does not represent a style, then `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::TimestampStyle;
+    ///
+    /// assert_eq!(TimestampStyle::from_char('R'), Some(TimestampStyle::RelativeTime));
+    /// assert_eq!(TimestampStyle::from_char('x'), None);
+    /// ```
+    pub fn from_char(c: char) -> Option<TimestampStyle> {
+        use self::TimestampStyle::*;
+
+        Some(match c {
+            't' => ShortTime,
+            'T' => LongTime,
+            'd' => ShortDate,
+            'D' => LongDate,
+            'f' => ShortDateTime,
+            'F' => LongDateTime,
+            'R' => RelativeTime,
+            _ => return None,
+        })
+    }
+}
+
+impl Default for TimestampStyle {
+    /// Creates the style used by the client when none is specified,
+    /// [`ShortDateTime`].
+    ///
+    /// [`ShortDateTime`]: #variant.ShortDateTime
+    fn default() -> TimestampStyle { TimestampStyle::ShortDateTime }
+}
+
+impl Display for TimestampStyle {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+/// Timestamp markup, which the Discord client renders as a time localised to
+/// the reader.
+///
+/// Formatting this via `Display` produces the markup in the form of
+/// `<t:unix:style>`, or `<t:unix>` if there is no style.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{Timestamp, TimestampStyle};
+///
+/// let timestamp = Timestamp {
+///     unix: 1618953630,
+///     style: Some(TimestampStyle::RelativeTime),
+/// };
+///
+/// assert_eq!(timestamp.to_string(), "<t:1618953630:R>");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Timestamp {
+    /// The number of seconds since the Unix epoch.
+    pub unix: i64,
+    /// The style to render the timestamp in, or `None` to use the default of
+    /// [`TimestampStyle::ShortDateTime`].
+    ///
+    /// [`TimestampStyle::ShortDateTime`]: enum.TimestampStyle.html#variant.ShortDateTime
+    pub style: Option<TimestampStyle>,
+}
+
+impl Timestamp {
+    /// Renders the timestamp as the Discord client would display it to a
+    /// reader in the `en-US` locale and the UTC timezone.
+    ///
+    /// The given time is used as the present when rendering relative times.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{Timestamp, TimestampStyle};
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let now = UNIX_EPOCH + Duration::from_secs(1618953630);
+    /// let mut timestamp = Timestamp {
+    ///     unix: 1618953630,
+    ///     style: None,
+    /// };
+    ///
+    /// assert_eq!(timestamp.render(now), "April 20, 2021 9:20 PM");
+    ///
+    /// timestamp.style = Some(TimestampStyle::LongDateTime);
+    /// assert_eq!(timestamp.render(now), "Tuesday, April 20, 2021 9:20 PM");
+    ///
+    /// timestamp.unix += 3 * 60 * 60;
+    /// timestamp.style = Some(TimestampStyle::RelativeTime);
+    /// assert_eq!(timestamp.render(now), "in 3 hours");
+    /// ```
+    pub fn render(&self, now: SystemTime) -> String {
+        self.render_with_offset(now, 0)
+    }
+
+    /// Renders the timestamp as the Discord client would display it to a
+    /// reader in the `en-US` locale, in a timezone the given number of seconds
+    /// ahead of UTC.
+    ///
+    /// The given time is used as the present when rendering relative times.
+    ///
+    /// # Examples
+    ///
+    /// Render a time for a reader in UTC-4:
+    ///
+    /// ```rust
+    /// use serenity_utils::{Timestamp, TimestampStyle};
+    /// use std::time::SystemTime;
+    ///
+    /// let timestamp = Timestamp {
+    ///     unix: 1618953630,
+    ///     style: Some(TimestampStyle::LongTime),
+    /// };
+    ///
+    /// assert_eq!(timestamp.render_with_offset(SystemTime::now(), -4 * 60 * 60), "5:20:30 PM");
+    /// ```
+    pub fn render_with_offset(&self, now: SystemTime, utc_offset: i32) -> String {
+        let style = self.style.unwrap_or_default();
+
+        if style == TimestampStyle::RelativeTime {
+            return render_relative(self.unix.saturating_sub(unix_secs(now)));
+        }
+
+        let local = self.unix.saturating_add(i64::from(utc_offset));
+        let days = local.div_euclid(86_400);
+        let secs = local.rem_euclid(86_400);
+        let (year, month, day) = civil_from_days(days);
+        let weekday = WEEKDAYS[(days + 4).rem_euclid(7) as usize];
+        let month_name = MONTHS[month as usize - 1];
+
+        let (hour, minute, second) = (secs / 3600, secs / 60 % 60, secs % 60);
+        let meridiem = if hour < 12 { "AM" } else { "PM" };
+        let hour = match hour % 12 {
+            0 => 12,
+            hour => hour,
+        };
+
+        match style {
+            TimestampStyle::ShortTime => format!("{}:{:02} {}", hour, minute, meridiem),
+            TimestampStyle::LongTime => {
+                format!("{}:{:02}:{:02} {}", hour, minute, second, meridiem)
+            },
+            TimestampStyle::ShortDate => format!("{:02}/{:02}/{}", month, day, year),
+            TimestampStyle::LongDate => format!("{} {}, {}", month_name, day, year),
+            TimestampStyle::ShortDateTime => format!(
+                "{} {}, {} {}:{:02} {}",
+                month_name, day, year, hour, minute, meridiem,
+            ),
+            TimestampStyle::LongDateTime => format!(
+                "{}, {} {}, {} {}:{:02} {}",
+                weekday, month_name, day, year, hour, minute, meridiem,
+            ),
+            TimestampStyle::RelativeTime => unreachable!(),
+        }
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self.style {
+            Some(style) => write!(f, "<t:{}:{}>", self.unix, style),
+            None => write!(f, "<t:{}>", self.unix),
+        }
+    }
+}
+
+/// Retrieves the time and style out of timestamp markup.
+///
+/// Markup both with a style (`<t:unix:style>`) and without one (`<t:unix>`)
+/// is supported.
+///
+/// If the markup is invalid, then `None` is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{Timestamp, TimestampStyle, parse_timestamp};
+///
+/// assert_eq!(parse_timestamp("<t:1618953630:R>"), Some(Timestamp {
+///     unix: 1618953630,
+///     style: Some(TimestampStyle::RelativeTime),
+/// }));
+///
+/// assert_eq!(parse_timestamp("<t:1618953630>").unwrap().style, None);
+/// ```
+///
+/// Asserting that invalid markup returns `None`:
+///
+/// ```rust
+/// use serenity_utils::parse_timestamp;
+///
+/// assert!(parse_timestamp("<t:1618953630:x>").is_none());
+/// assert!(parse_timestamp("<t:1618953630").is_none());
+/// ```
+pub fn parse_timestamp(markup: &str) -> Option<Timestamp> {
+    let inner = markup.strip_prefix("<t:")?.strip_suffix('>')?;
+
+    let (unix, style) = match inner.find(':') {
+        Some(pos) => {
+            let mut style = inner[pos + 1..].chars();
+            let c = style.next()?;
+
+            if style.next().is_some() {
+                return None;
+            }
+
+            (&inner[..pos], Some(TimestampStyle::from_char(c)?))
+        },
+        None => (inner, None),
+    };
+
+    let digits = unix.strip_prefix('-').unwrap_or(unix);
+
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(Timestamp {
+        unix: unix.parse().ok()?,
+        style,
+    })
+}
+
+/// Renders a number of seconds from the present as a relative time, using the
+/// same thresholds as the Discord client.
+fn render_relative(delta: i64) -> String {
+    let seconds = delta.unsigned_abs() as f64;
+    let minutes = (seconds / 60.0).round();
+    let hours = (seconds / 3600.0).round();
+    let days = (seconds / 86_400.0).round();
+    let months = (seconds / 86_400.0 * 4800.0 / 146_097.0).round();
+    let years = (seconds / 86_400.0 * 400.0 / 146_097.0).round();
+
+    let amount = if seconds < 45.0 {
+        "a few seconds".to_owned()
+    } else if minutes <= 1.0 {
+        "a minute".to_owned()
+    } else if minutes < 45.0 {
+        format!("{} minutes", minutes)
+    } else if hours <= 1.0 {
+        "an hour".to_owned()
+    } else if hours < 22.0 {
+        format!("{} hours", hours)
+    } else if days <= 1.0 {
+        "a day".to_owned()
+    } else if days < 26.0 {
+        format!("{} days", days)
+    } else if months <= 1.0 {
+        "a month".to_owned()
+    } else if months < 11.0 {
+        format!("{} months", months)
+    } else if years <= 1.0 {
+        "a year".to_owned()
+    } else {
+        format!("{} years", years)
+    };
+
+    if delta > 0 {
+        format!("in {}", amount)
+    } else {
+        format!("{} ago", amount)
+    }
+}
+
+/// Converts a time into seconds since the Unix epoch.
+fn unix_secs(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(why) => -(why.duration().as_secs() as i64),
+    }
+}
+
+/// Converts a number of days since the Unix epoch into a proleptic Gregorian
+/// `(year, month, day)` date.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
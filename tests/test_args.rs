@@ -0,0 +1,234 @@
+extern crate serenity_utils;
+
+use serenity_utils::*;
+
+#[test]
+fn default_tokenizer_matches_parse_quotes() {
+    let inputs = [
+        "a \"b c\" d\"e f\"  g",
+        "\"unterminated quote",
+        "\"escaped \\\" quote\" outside\\ escape",
+        "\"\" empty",
+        "tabs\tare not\nseparators",
+    ];
+
+    for input in &inputs {
+        assert_eq!(Tokenizer::default().split(input), parse_quotes(input));
+    }
+
+    assert_eq!(parse_quotes("\"escaped \\\" quote\" outside\\ escape"),
+               ["escaped \" quote", "outside\\", "escape"]);
+}
+
+#[test]
+fn shell_tokenizer() {
+    let tokenizer = Tokenizer::shell();
+
+    assert_eq!(tokenizer.split("a\tb\nc\r\nd"), ["a", "b", "c", "d"]);
+    assert_eq!(tokenizer.split("'single' \"double\" “smart” ‘single smart’"),
+               ["single", "double", "smart", "single smart"]);
+    assert_eq!(tokenizer.split("escaped\\ space 'it''s'"), ["escaped space", "it", "s"]);
+    assert_eq!(tokenizer.split("“mixed\" quotes”"), ["mixed\" quotes"]);
+}
+
+#[test]
+fn custom_tokenizer() {
+    let tokenizer = Tokenizer::new()
+        .delimiters(&[','])
+        .quotes(&[('(', ')')])
+        .escape(None)
+        .keep_quotes(true);
+
+    assert_eq!(tokenizer.split("a b,(c,\\d),e"), ["a b", "(c,\\d)", "e"]);
+}
+
+#[test]
+fn tokens_borrow_unless_escaped() {
+    use std::borrow::Cow;
+
+    let input = "plain \"quoted arg\" \"esc\\\"aped\" tail";
+    let tokens = parse_quotes_iter(input).collect::<Vec<_>>();
+
+    assert_eq!(tokens.len(), 4);
+    assert_eq!(tokens[0], Token { value: Cow::Borrowed("plain"), offset: 0 });
+    assert_eq!(tokens[1], Token { value: Cow::Borrowed("quoted arg"), offset: 6 });
+    assert_eq!(tokens[2].value, "esc\"aped");
+    assert_eq!(tokens[2].offset, 19);
+    assert_eq!(tokens[3], Token { value: Cow::Borrowed("tail"), offset: 31 });
+
+    assert!(matches!(tokens[1].value, Cow::Borrowed(_)));
+    assert!(matches!(tokens[2].value, Cow::Owned(_)));
+}
+
+#[test]
+fn tokens_with_keep_quotes_and_outside_escapes() {
+    let tokenizer = Tokenizer::shell().keep_quotes(true);
+    let tokens = tokenizer.tokens("a\\ b 'c\\'d' “e”").collect::<Vec<_>>();
+    let values = tokens.iter().map(|token| &token.value[..]).collect::<Vec<_>>();
+    let offsets = tokens.iter().map(|token| token.offset).collect::<Vec<_>>();
+
+    assert_eq!(values, ["a b", "'c'd'", "“e”"]);
+    assert_eq!(offsets, [0, 5, 12]);
+}
+
+#[test]
+fn args_cursor() {
+    let mut args = Args::new("<@!12345> 67890 \"two words\"  trailing text ");
+
+    assert_eq!(args.len(), 5);
+    assert_eq!(args.single::<UserArg>().unwrap(), UserArg(12_345));
+    assert_eq!(args.optional::<bool>(), None);
+    assert_eq!(args.offset(), Some(10));
+    assert_eq!(args.single::<Snowflake>().unwrap(), Snowflake(67_890));
+    assert_eq!(args.single::<String>().unwrap(), "two words");
+    assert_eq!(args.rest(), "trailing text");
+    assert!(args.is_empty());
+    assert_eq!(args.rest(), "");
+
+    match args.single::<String>() {
+        Err(Error::Parse(ParseError::MissingArgument)) => {},
+        other => panic!("expected a missing argument, got {:?}", other),
+    }
+}
+
+#[test]
+fn args_multiple() {
+    let mut args = Args::new("1 2 x");
+
+    match args.multiple::<u64>() {
+        Err(Error::Parse(ParseError::InvalidInteger)) => {},
+        other => panic!("expected an invalid integer, got {:?}", other),
+    }
+
+    assert_eq!(args.len(), 3);
+    assert_eq!(args.multiple::<String>().unwrap(), ["1", "2", "x"]);
+    assert!(args.multiple::<String>().is_err());
+}
+
+#[test]
+fn from_arg_impls() {
+    use std::time::Duration;
+
+    assert_eq!(u64::from_arg("42").unwrap(), 42);
+    assert_eq!(i64::from_arg("-42").unwrap(), -42);
+    assert!(u8::from_arg("256").is_err());
+    assert_eq!(f64::from_arg("1.5").unwrap(), 1.5);
+    assert!(bool::from_arg("Yes").unwrap());
+    assert!(!bool::from_arg("off").unwrap());
+    assert!(bool::from_arg("maybe").is_err());
+    assert_eq!(Colour::from_arg("#7289DA").unwrap(), Colour::blurple());
+    assert_eq!(Colour::from_arg("0x7289da").unwrap(), Colour::blurple());
+    assert!(Colour::from_arg("#7289D").is_err());
+    assert!(Colour::from_arg("red❤").is_err());
+    assert_eq!(Colour::from_arg("dark teal").unwrap(), Colour::dark_teal());
+    assert_eq!(Duration::from_arg("90").unwrap(), Duration::from_secs(90));
+    assert_eq!(Duration::from_arg("1h30m").unwrap(), Duration::from_secs(5_400));
+    assert_eq!(UserArg::from_arg("12345").unwrap(), UserArg(12_345));
+    assert_eq!(RoleArg::from_arg("<@&12345>").unwrap(), RoleArg(12_345));
+    assert_eq!(ChannelArg::from_arg("<#12345>").unwrap(), ChannelArg(12_345));
+    assert!(ChannelArg::from_arg("<@12345>").is_err());
+
+    let emoji = EmojiArg::from_arg("<a:name:12345>").unwrap();
+    assert!(emoji.animated);
+    assert_eq!(emoji.name, "name");
+    assert_eq!(emoji.id, 12_345);
+}
+
+fn option_parser() -> OptionParser {
+    OptionParser::new()
+        .option("reason", Some('r'))
+        .option("days", Some('d'))
+        .flag("silent", Some('s'))
+        .flag("force", Some('f'))
+}
+
+#[test]
+fn options_forms() {
+    let parser = option_parser();
+
+    let options = parser.parse(r#"user --reason "no spam" --days=7 -sf"#).unwrap();
+    assert_eq!(options.positional().len(), 1);
+    assert_eq!(options.positional()[0].value, "user");
+    assert_eq!(options.value("reason"), Some("no spam"));
+    assert_eq!(options.get::<u64>("days").unwrap(), Some(7));
+    assert!(options.has("silent") && options.has("force"));
+
+    let options = parser.parse("-d3 -r spam reason:other days=1 user").unwrap();
+    assert_eq!(options.values("days").collect::<Vec<_>>(), ["3", "1"]);
+    assert_eq!(options.value("days"), Some("1"));
+    assert_eq!(options.value("reason"), Some("other"));
+    assert!(!options.has("silent"));
+    assert_eq!(options.get::<bool>("silent").unwrap(), None);
+    assert_eq!(options.positional()[0].value, "user");
+}
+
+#[test]
+fn options_positional() {
+    let options = option_parser().parse("-5 - -0.5 https://example.com x=y -- --force").unwrap();
+    let positional = options.positional()
+        .iter()
+        .map(|token| &token.value[..])
+        .collect::<Vec<_>>();
+
+    assert_eq!(positional, ["-5", "-", "-0.5", "https://example.com", "x=y", "--force"]);
+    assert!(!options.has("force"));
+    assert_eq!(options.positional()[5].offset, 37);
+}
+
+#[test]
+fn options_errors() {
+    let parser = option_parser();
+
+    match parser.parse("--unknown=1") {
+        Err(Error::Parse(ParseError::UnknownOption(0))) => {},
+        other => panic!("expected an unknown option, got {:?}", other),
+    }
+
+    match parser.parse("user -sx") {
+        Err(Error::Parse(ParseError::UnknownOption(5))) => {},
+        other => panic!("expected an unknown option, got {:?}", other),
+    }
+
+    match parser.parse("user --reason") {
+        Err(Error::Parse(ParseError::MissingOptionValue("reason"))) => {},
+        other => panic!("expected a missing value, got {:?}", other),
+    }
+
+    for input in &["--reason --silent", "--reason -s", "-r -fs", "--reason --days=1", "--reason --"] {
+        match parser.parse(input) {
+            Err(Error::Parse(ParseError::MissingOptionValue("reason"))) => {},
+            other => panic!("expected a missing value for {:?}, got {:?}", input, other),
+        }
+    }
+
+    let options = parser.parse("--reason=--silent -r--force --reason -5").unwrap();
+    assert_eq!(options.values("reason").collect::<Vec<_>>(), ["--silent", "--force", "-5"]);
+    assert!(!options.has("silent") && !options.has("force"));
+    assert_eq!(parser.parse("--reason --unknown").unwrap().value("reason"), Some("--unknown"));
+
+    for input in &["--silent=no", "silent=no", "silent:no"] {
+        match parser.parse(input) {
+            Err(Error::Parse(ParseError::UnexpectedOptionValue("silent"))) => {},
+            other => panic!("expected an unexpected value, got {:?}", other),
+        }
+    }
+
+    assert_eq!(Error::from(ParseError::UnknownOption(5)).to_string(), "Option is not recognised at byte 5");
+    assert_eq!(ParseError::MissingOptionValue("reason").to_string(), "Option is missing a value: reason");
+    assert!(parser.parse("--days=x").unwrap().get::<u64>("days").is_err());
+}
+
+#[test]
+fn args_borrow_message() {
+    let message = String::from("ban \"some user\" 7");
+
+    let mut args = {
+        let tokenizer = Tokenizer::shell();
+
+        Args::with_tokenizer(&message, &tokenizer)
+    };
+
+    assert_eq!(args.current().unwrap().as_ptr(), message.as_ptr());
+    args.single::<String>().unwrap();
+    assert_eq!(args.current().unwrap().as_ptr(), message[5..].as_ptr());
+}
@@ -0,0 +1,198 @@
+extern crate serenity_utils;
+
+use serenity_utils::*;
+
+fn hex(value: u32) -> Colour { Colour::new(value) }
+
+#[test]
+fn lighten_darken() {
+    assert_eq!(hex(0x6B717F).lighten(0.2), hex(0xA1A5AF));
+    assert_eq!(hex(0x003366).lighten(0.6), hex(0x99CCFF));
+    assert_eq!(hex(0xE1D7D2).lighten(0.3), hex(0xFFFFFF));
+    assert_eq!(hex(0xB37399).darken(0.2), hex(0x7C4465));
+    assert_eq!(hex(0xF2ECE4).darken(0.4), hex(0xB08B5A));
+    assert_eq!(hex(0x003366).darken(0.3), hex(0x000000));
+    assert_eq!(Colour::blurple().lighten(0.0), Colour::blurple());
+}
+
+#[test]
+fn saturate_desaturate() {
+    assert_eq!(hex(0xCC6699).saturate(0.2), hex(0xE05299));
+    assert_eq!(hex(0x0E4982).saturate(0.3), hex(0x004990));
+    assert_eq!(hex(0x003366).desaturate(0.2), hex(0x0A335C));
+    assert_eq!(hex(0xF2ECE4).desaturate(0.2), hex(0xEEEBE8));
+    assert_eq!(hex(0xD2E1DD).desaturate(0.3), hex(0xDADADA));
+}
+
+#[test]
+fn grayscale_complement_invert() {
+    assert_eq!(hex(0x6B717F).grayscale(), hex(0x757575));
+    assert_eq!(hex(0xD2E1DD).grayscale(), hex(0xDADADA));
+    assert_eq!(hex(0x003366).grayscale(), hex(0x333333));
+    assert_eq!(hex(0x6B717F).complement(), hex(0x7F796B));
+    assert_eq!(hex(0xD2E1DD).complement(), hex(0xE1D2D6));
+    assert_eq!(hex(0x003366).complement(), hex(0x663300));
+    assert_eq!(hex(0xB37399).invert(), hex(0x4C8C66));
+    assert_eq!(hex(0x000000).invert(), hex(0xFFFFFF));
+}
+
+#[test]
+fn mix() {
+    let a = hex(0x003366);
+    let b = hex(0xD2E1DD);
+
+    assert_eq!(a.mix(b, 0.5), hex(0x698AA2));
+    assert_eq!(a.mix(b, 0.25), hex(0x355F84));
+    assert_eq!(a.mix(b, 0.75), hex(0x9EB6BF));
+    assert_eq!(a.mix(b, 0.0), a);
+    assert_eq!(a.mix(b, 1.0), b);
+    assert_eq!(a.mix(b, 2.0), b);
+}
+
+#[test]
+fn contrast() {
+    let approx = |a: f64, b: f64| (a - b).abs() < 0.005;
+
+    assert!(approx(Colour::blurple().luminance(), 0.2653));
+    assert!(approx(hex(0x777777).contrast_ratio(hex(0xFFFFFF)), 4.48));
+    assert!(approx(hex(0xFFFFFF).contrast_ratio(hex(0x767676)), 4.54));
+    assert!(approx(Colour::blurple().contrast_ratio(Colour::blurple()), 1.0));
+
+    assert!(!hex(0x777777).is_readable_on(Colour::light_theme()));
+    assert!(hex(0x767676).is_readable_on(Colour::light_theme()));
+    assert!(!Colour::blurple().is_readable_on(Colour::dark_theme()));
+    assert!(Colour::lighter_grey().is_readable_on(Colour::dark_theme()));
+    assert!(!hex(0x000000).is_readable_on(Colour::dark_theme()));
+
+    assert_eq!(Colour::dark_theme().tuple(), (49, 51, 56));
+}
+
+#[test]
+fn presets() {
+    let presets = Colour::presets().collect::<Vec<_>>();
+
+    assert_eq!(presets.len(), Colour::presets().len());
+    assert!(presets.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert!(presets.contains(&("blurple", Colour::blurple())));
+
+    for (name, colour) in presets {
+        assert_eq!(Colour::by_name(name), Some(colour));
+        assert_eq!(colour.nearest_preset(), (name, colour));
+    }
+}
+
+#[test]
+fn by_name() {
+    assert_eq!(Colour::by_name("BLURPLE"), Some(Colour::blurple()));
+    assert_eq!(Colour::by_name("dark-teal"), Some(Colour::dark_teal()));
+    assert_eq!(Colour::by_name("dark grey"), Some(Colour::dark_grey()));
+    assert_eq!(Colour::by_name("darkgrey"), Some(Colour::dark_grey()));
+    assert_eq!(Colour::by_name("DarkGray"), Some(hex(0xA9A9A9)));
+    assert_eq!(Colour::by_name("rebecca_purple"), Some(hex(0x663399)));
+    assert_eq!(Colour::by_name("red"), Some(Colour::red()));
+    assert_eq!(Colour::by_name("Cornflower Blue"), Some(hex(0x6495ED)));
+    assert_eq!(Colour::by_name("rebeccapurple"), Some(hex(0x663399)));
+    assert_eq!(Colour::by_name("grey"), Some(hex(0x808080)));
+    assert_eq!(Colour::by_name(""), None);
+    assert_eq!(Colour::by_name("blurple2"), None);
+    assert_eq!(Colour::by_name("dark theme"), None);
+    assert_eq!(Colour::by_name("white"), Some(Colour::light_theme()));
+    assert_eq!("alice blue".parse::<Colour>().unwrap(), hex(0xF0F8FF));
+}
+
+#[test]
+fn nearest() {
+    assert_eq!(hex(0x7088DD).nearest_preset().0, "blurple");
+    assert_eq!(hex(0xE84D3D).nearest_preset().0, "red");
+    assert_ne!(hex(0xFFFFFF).nearest_preset().0, "light_theme");
+
+    assert_eq!(Colour::blurple().nearest_css_name().0, "cornflowerblue");
+    assert_eq!(Colour::dark_teal().nearest_css_name().0, "teal");
+    assert_eq!(Colour::gold().nearest_css_name().0, "gold");
+    assert_eq!(hex(0xFF0000).nearest_css_name(), ("red", hex(0xFF0000)));
+    assert_eq!(hex(0x00FFFF).nearest_css_name().0, "aqua");
+}
+
+#[test]
+fn schemes() {
+    assert_eq!(hex(0xFF0000).analogous(), [hex(0xFF0080), hex(0xFF0000), hex(0xFF8000)]);
+    assert_eq!(hex(0xFF0000).triadic(), [hex(0xFF0000), hex(0x00FF00), hex(0x0000FF)]);
+    assert_eq!(Colour::blurple().triadic()[0], Colour::blurple());
+    assert_eq!(hex(0x808080).triadic(), [hex(0x808080); 3]);
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(hex(0x7289DA).to_hsl().0.round(), 227.0);
+    assert_eq!(hex(0x7289DA).to_hsv().0.round(), 227.0);
+    assert_eq!(hex(0xFFFF00).to_hsv(), (60.0, 1.0, 1.0));
+    assert_eq!(Colour::from_hsv(420.0, 2.0, 1.0), hex(0xFFFF00));
+
+    let (l, a, b) = hex(0x0000FF).to_lab();
+    assert_eq!((l.round(), a.round(), b.round()), (32.0, 79.0, -108.0));
+
+    let (l, a, b) = hex(0x000000).to_lab();
+    assert_eq!((l, a, b), (0.0, 0.0, 0.0));
+}
+
+#[test]
+fn conversion_round_trips() {
+    let colours = (0..0x100_0000).step_by(9973).chain(Some(0xFFFFFF)).map(hex);
+
+    for colour in colours {
+        let (h, s, l) = colour.to_hsl();
+        assert_eq!(Colour::from_hsl(h, s, l), colour);
+
+        let (h, s, v) = colour.to_hsv();
+        assert_eq!(Colour::from_hsv(h, s, v), colour);
+
+        let (l, a, b) = colour.to_lab();
+        assert_eq!(Colour::from_lab(l, a, b), colour);
+
+        let (l, a, b) = colour.to_oklab();
+        assert_eq!(Colour::from_oklab(l, a, b), colour);
+    }
+}
+
+#[test]
+fn gradients() {
+    let stops = [hex(0x000000), hex(0xFFFFFF)];
+
+    assert_eq!(gradient(&stops, 3, Interpolation::Rgb), [hex(0x000000), hex(0x808080), hex(0xFFFFFF)]);
+    assert_eq!(gradient(&stops, 3, Interpolation::Oklab), [hex(0x000000), hex(0x636363), hex(0xFFFFFF)]);
+    assert_eq!(gradient(&stops, 0, Interpolation::Rgb), []);
+    assert_eq!(gradient(&stops, 1, Interpolation::Rgb), [hex(0x000000)]);
+    assert_eq!(gradient(&[], 3, Interpolation::Rgb), []);
+    assert_eq!(gradient(&[Colour::blurple()], 2, Interpolation::Oklab), [Colour::blurple(); 2]);
+
+    let stops = [hex(0xFF0000), hex(0x00FF00), hex(0x0000FF)];
+    let colours = gradient(&stops, 5, Interpolation::Rgb);
+    assert_eq!(colours, [hex(0xFF0000), hex(0x808000), hex(0x00FF00), hex(0x008080), hex(0x0000FF)]);
+
+    // Interpolating between a colour and itself must return that colour, so
+    // OKLab conversions have to round trip.
+    for (_, colour) in Colour::presets() {
+        assert_eq!(gradient(&[colour, colour], 3, Interpolation::Oklab), [colour; 3]);
+    }
+
+    for value in (0..0x100_0000).step_by(0x1_0101) {
+        assert_eq!(gradient(&[hex(value); 2], 2, Interpolation::default()), [hex(value); 2]);
+    }
+}
+
+#[test]
+fn hue_palettes() {
+    assert_eq!(hue_palette(0), []);
+
+    let colours = hue_palette(8);
+    assert_eq!(colours.len(), 8);
+
+    for (idx, a) in colours.iter().enumerate() {
+        for b in &colours[idx + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+
+    let luminances = colours.iter().map(Colour::luminance).collect::<Vec<_>>();
+    assert!(luminances.iter().all(|l| (l - luminances[0]).abs() < 0.15));
+}
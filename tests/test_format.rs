@@ -0,0 +1,162 @@
+extern crate serenity_utils;
+#[macro_use]
+extern crate quickcheck;
+
+use quickcheck::TestResult;
+use serenity_utils::*;
+
+/// Turns an arbitrary string into a valid emoji or command name, if possible.
+fn emoji_name(name: &str) -> Option<String> {
+    let name = name.chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .take(32)
+        .collect::<String>();
+
+    if name.is_empty() { None } else { Some(name) }
+}
+
+/// Checks that a mention of an Id is parsed back into the same Id, both by
+/// its own parser and by `parse_mention`.
+fn id_round_trip(
+    id: u64,
+    mention: fn(u64) -> Mention<'static>,
+    parse: fn(&str) -> Option<u64>,
+) -> TestResult {
+    if id == 0 {
+        return TestResult::discard();
+    }
+
+    let mention = mention(id);
+    let formatted = mention.to_string();
+
+    TestResult::from_bool(parse(&formatted) == Some(id) && parse_mention(&formatted) == Some(mention))
+}
+
+quickcheck! {
+    fn user_round_trip(id: u64) -> TestResult {
+        id_round_trip(id, Mention::User, parse_username)
+    }
+
+    fn nickname_round_trip(id: u64) -> TestResult {
+        id_round_trip(id, Mention::Nickname, parse_username)
+    }
+
+    fn role_round_trip(id: u64) -> TestResult {
+        id_round_trip(id, Mention::Role, parse_role)
+    }
+
+    fn channel_round_trip(id: u64) -> TestResult {
+        id_round_trip(id, Mention::Channel, parse_channel)
+    }
+
+    fn emoji_round_trip(name: String, id: u64, animated: bool) -> TestResult {
+        let name = match emoji_name(&name) {
+            Some(name) => name,
+            None => return TestResult::discard(),
+        };
+
+        if id == 0 {
+            return TestResult::discard();
+        }
+
+        let emoji = EmojiRef {
+            animated,
+            name: &name,
+            id,
+        };
+        let mention = if animated {
+            Mention::AnimatedEmoji(&name, id)
+        } else {
+            Mention::Emoji(&name, id)
+        };
+
+        TestResult::from_bool(parse_emoji(&emoji.to_string()) == Some(emoji) &&
+                              parse_mention(&mention.to_string()) == Some(mention))
+    }
+}
+
+quickcheck! {
+    fn command_round_trip(names: Vec<String>, id: u64) -> TestResult {
+        let names = names.iter()
+            .filter_map(|name| emoji_name(name))
+            .take(3)
+            .collect::<Vec<_>>();
+
+        if names.is_empty() || id == 0 {
+            return TestResult::discard();
+        }
+
+        let (subcommand_group, subcommand) = match names.len() {
+            3 => (Some(&names[1][..]), Some(&names[2][..])),
+            2 => (None, Some(&names[1][..])),
+            _ => (None, None),
+        };
+        let command = CommandMention {
+            name: &names[0],
+            subcommand_group,
+            subcommand,
+            id,
+        };
+
+        TestResult::from_bool(parse_command(&command.to_string()) == Some(command))
+    }
+}
+
+#[test]
+fn everyone_and_here_round_trip() {
+    assert_eq!(parse_mention(&Mention::Everyone.to_string()), Some(Mention::Everyone));
+    assert_eq!(parse_mention(&Mention::Here.to_string()), Some(Mention::Here));
+}
+
+#[test]
+fn command_mention_format() {
+    let mut command = CommandMention {
+        name: "permissions",
+        subcommand_group: Some("user"),
+        subcommand: Some("get"),
+        id: 12_345,
+    };
+    assert_eq!(command.to_string(), "</permissions user get:12345>");
+
+    command.subcommand_group = None;
+    command.subcommand = None;
+    assert_eq!(command.to_string(), "</permissions:12345>");
+}
+
+#[test]
+fn timestamp_format() {
+    let mut timestamp = Timestamp {
+        unix: 1_618_953_630,
+        style: None,
+    };
+    assert_eq!(timestamp.to_string(), "<t:1618953630>");
+
+    timestamp.style = Some(TimestampStyle::LongDate);
+    assert_eq!(timestamp.to_string(), "<t:1618953630:D>");
+}
+
+quickcheck! {
+    fn timestamp_round_trip(unix: i64, style: Option<u8>) -> bool {
+        let styles = [
+            TimestampStyle::ShortTime,
+            TimestampStyle::LongTime,
+            TimestampStyle::ShortDate,
+            TimestampStyle::LongDate,
+            TimestampStyle::ShortDateTime,
+            TimestampStyle::LongDateTime,
+            TimestampStyle::RelativeTime,
+        ];
+        let timestamp = Timestamp {
+            unix,
+            style: style.map(|style| styles[style as usize % styles.len()]),
+        };
+
+        parse_timestamp(&timestamp.to_string()) == Some(timestamp)
+    }
+
+    fn duration_round_trip(secs: u64) -> bool {
+        let duration = std::time::Duration::from_secs(secs);
+
+        parse_duration(&format_duration(duration)).ok() == Some(duration)
+    }
+}
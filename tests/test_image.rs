@@ -0,0 +1,103 @@
+#![cfg(feature = "image")]
+
+extern crate image;
+extern crate serenity_utils;
+
+use image::codecs::png::PngEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::{ExtendedColorType, ImageEncoder, ImageError};
+use serenity_utils::*;
+
+/// Encodes RGBA pixels as a PNG image of the given width.
+fn png(width: u32, pixels: &[[u8; 4]]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    encode(PngEncoder::new(&mut bytes), width, pixels);
+
+    bytes
+}
+
+/// Encodes RGBA pixels as a lossless WebP image of the given width.
+fn webp(width: u32, pixels: &[[u8; 4]]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    encode(WebPEncoder::new_lossless(&mut bytes), width, pixels);
+
+    bytes
+}
+
+fn encode<E: ImageEncoder>(encoder: E, width: u32, pixels: &[[u8; 4]]) {
+    let data = pixels.iter().flat_map(|pixel| pixel.iter().cloned()).collect::<Vec<_>>();
+    let height = pixels.len() as u32 / width;
+
+    encoder.write_image(&data, width, height, ExtendedColorType::Rgba8).unwrap();
+}
+
+#[test]
+fn dominant() {
+    let red = [0xE7, 0x4C, 0x3C, 0xFF];
+    let blue = [0x72, 0x89, 0xDA, 0xFF];
+    let mut pixels = vec![red; 60];
+    pixels.extend(vec![blue; 40]);
+
+    let image = png(10, &pixels);
+
+    assert_eq!(dominant_colour(&image).unwrap(), Colour::red());
+    assert_eq!(colour_palette(&image, 4).unwrap(), [Colour::red(), Colour::blurple()]);
+    assert_eq!(colour_palette(&image, 1).unwrap().len(), 1);
+    assert_eq!(colour_palette(&image, 0).unwrap(), []);
+}
+
+#[test]
+fn formats() {
+    let red = [0xE7, 0x4C, 0x3C, 0xFF];
+    let blue = [0x72, 0x89, 0xDA, 0xFF];
+    let mut pixels = vec![red; 60];
+    pixels.extend(vec![blue; 40]);
+
+    let image = webp(10, &pixels);
+
+    assert_eq!(dominant_colour(&image).unwrap(), Colour::red());
+    assert_eq!(colour_palette(&image, 4).unwrap(), [Colour::red(), Colour::blurple()]);
+}
+
+#[test]
+fn transparency() {
+    let clear = [0xFF, 0xFF, 0xFF, 0x00];
+    let gold = [0xF1, 0xC4, 0x0F, 0xFF];
+    let mut pixels = vec![clear; 90];
+    pixels.extend(vec![gold; 10]);
+
+    assert_eq!(dominant_colour(&png(10, &pixels)).unwrap(), Colour::gold());
+    assert_eq!(dominant_colour(&png(2, &[clear; 4])).unwrap(), Colour::default());
+    assert_eq!(colour_palette(&png(2, &[clear; 4]), 3).unwrap(), []);
+}
+
+#[test]
+fn large_and_deterministic() {
+    let pixels = (0..200 * 200)
+        .map(|idx| if idx % 200 < 150 { [0x11, 0x80, 0x6A, 0xFF] } else { [0xFF, 0xFF, 0xFF, 0xFF] })
+        .collect::<Vec<_>>();
+    let image = png(200, &pixels);
+
+    let palette = colour_palette(&image, 5).unwrap();
+    assert_eq!(palette[0].nearest_preset().0, "dark_teal");
+    assert_eq!(colour_palette(&image, 5).unwrap(), palette);
+}
+
+#[test]
+fn invalid() {
+    match dominant_colour(b"not an image") {
+        Err(Error::Image(ImageError::Unsupported(_))) => {},
+        other => panic!("expected an unsupported format, got {:?}", other),
+    }
+
+    // Changing the width of the image breaks the checksum of its header.
+    let mut corrupt = png(2, &[[0xFF, 0xFF, 0xFF, 0xFF]; 4]);
+    corrupt[19] ^= 0xFF;
+
+    match dominant_colour(&corrupt) {
+        Err(err @ Error::Image(ImageError::Decoding(_))) => {
+            assert!(err.to_string().starts_with("Image could not be decoded: "));
+        },
+        other => panic!("expected a decoding error, got {:?}", other),
+    }
+}
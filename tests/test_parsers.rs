@@ -1,12 +1,58 @@
 extern crate serenity_utils;
 
 use serenity_utils::*;
+use std::fmt::Debug;
+
+/// Returns the reason that parsing failed, panicking if it succeeded.
+fn reason<T: Debug>(result: Result<T>) -> ParseError {
+    match result {
+        Err(Error::Parse(why)) => why,
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
 
 #[test]
 fn invite_parser() {
     assert_eq!(parse_invite("https://discord.gg/abc"), "abc");
     assert_eq!(parse_invite("http://discord.gg/abc"), "abc");
     assert_eq!(parse_invite("discord.gg/abc"), "abc");
+    assert_eq!(parse_invite("https://discord.com/invite/abc"), "abc");
+    assert_eq!(parse_invite("abc"), "abc");
+}
+
+#[test]
+fn invite_url_parser() {
+    let code = |url| parse_invite_url(url).map(|invite| invite.code);
+
+    assert_eq!(code("https://discord.gg/abc"), Some("abc"));
+    assert_eq!(code("https://www.discord.gg/abc"), Some("abc"));
+    assert_eq!(code("https://discord.com/invite/abc"), Some("abc"));
+    assert_eq!(code("discordapp.com/invite/abc"), Some("abc"));
+    assert_eq!(code("HTTPS://Discord.GG/aBc-1"), Some("aBc-1"));
+    assert_eq!(code("<https://discord.gg/abc>"), Some("abc"));
+    assert_eq!(code("https://discord.gg/abc/"), Some("abc"));
+    assert_eq!(code("https://discord.gg/abc?utm_source=x#top"), Some("abc"));
+    assert_eq!(code("abc"), None);
+    assert_eq!(code("https://discord.gg/"), None);
+    assert_eq!(code("https://discord.gg/abc/def"), None);
+    assert_eq!(code("https://discord.com/abc"), None);
+    assert_eq!(code("https://discord.ggg/abc"), None);
+
+    let invite = parse_invite_url("discord.gg/abc?event=123&x=y").unwrap();
+    assert_eq!(invite.event_id, Some(123));
+
+    let duplicates = [
+        "discord.gg/abc?event=123&event=",
+        "discord.gg/abc?event=123&event=x",
+        "discord.gg/abc?event=&event=123",
+    ];
+
+    for url in &duplicates {
+        assert_eq!(parse_invite_url(url).unwrap().event_id, Some(123), "{}", url);
+    }
+
+    assert_eq!(parse_invite_url("discord.gg/abc?event=1&event=2").unwrap().event_id, Some(1));
+    assert_eq!(find_invites("discord.gg/abc?event=1&event=0").next().unwrap().event_id, Some(1));
 }
 
 #[test]
@@ -28,8 +74,16 @@ fn channel_parser() {
 #[test]
 fn emoji_parser() {
     let emoji = parse_emoji("<:name:12345>").unwrap();
-    assert_eq!(emoji.0, "name");
-    assert_eq!(emoji.1, 12_345);
+    assert!(!emoji.animated);
+    assert_eq!(emoji.name, "name");
+    assert_eq!(emoji.id, 12_345);
+    assert_eq!(emoji.url(), "https://cdn.discordapp.com/emojis/12345.png");
+
+    let animated = parse_emoji("<a:name:12345>").unwrap();
+    assert!(animated.animated);
+    assert_eq!(animated.name, "name");
+    assert_eq!(animated.id, 12_345);
+    assert_eq!(animated.url(), "https://cdn.discordapp.com/emojis/12345.gif");
 }
 
 #[test]
@@ -37,3 +91,278 @@ fn quote_parser() {
     let parsed = parse_quotes("a \"b c\" d\"e f\"  g");
     assert_eq!(parsed, ["a", "b c", "d", "e f", "g"]);
 }
+
+#[test]
+fn mention_parser() {
+    assert_eq!(parse_mention("<@12345>"), Some(Mention::User(12_345)));
+    assert_eq!(parse_mention("<@!12345>"), Some(Mention::Nickname(12_345)));
+    assert_eq!(parse_mention("<@&12345>"), Some(Mention::Role(12_345)));
+    assert_eq!(parse_mention("<#12345>"), Some(Mention::Channel(12_345)));
+    assert_eq!(parse_mention("<:name:12345>"), Some(Mention::Emoji("name", 12_345)));
+    assert_eq!(parse_mention("<a:name:12345>"), Some(Mention::AnimatedEmoji("name", 12_345)));
+    assert_eq!(parse_mention("@everyone"), Some(Mention::Everyone));
+    assert_eq!(parse_mention("@here"), Some(Mention::Here));
+    assert_eq!(parse_mention("<a:name12345>"), None);
+    assert_eq!(parse_mention("everyone"), None);
+}
+
+#[test]
+fn mention_scanner() {
+    let text = "<@1> a <@!2><#3> <@&4 <@&5> <:e:6>@here <a:f:7>";
+    let found = mentions(text).collect::<Vec<_>>();
+
+    assert_eq!(found, vec![
+        (Mention::User(1), 0..4),
+        (Mention::Nickname(2), 7..12),
+        (Mention::Channel(3), 12..16),
+        (Mention::Role(5), 22..27),
+        (Mention::Emoji("e", 6), 28..34),
+        (Mention::Here, 34..39),
+        (Mention::AnimatedEmoji("f", 7), 40..47),
+    ]);
+}
+
+#[test]
+fn strict_username_parser() {
+    assert_eq!(try_parse_username("<@12345>").unwrap(), 12_345);
+    assert_eq!(try_parse_username("<@!12345>").unwrap(), 12_345);
+    assert_eq!(reason(try_parse_username("12345")), ParseError::WrongPrefix);
+    assert_eq!(reason(try_parse_username("<@&12345>")), ParseError::WrongPrefix);
+    assert_eq!(reason(try_parse_username("<@12345x")), ParseError::MissingClosingBracket);
+    assert_eq!(reason(try_parse_username("<@12345>x")), ParseError::MissingClosingBracket);
+    assert_eq!(reason(try_parse_username("<@!>")), ParseError::EmptyId);
+    assert_eq!(reason(try_parse_username("<@>")), ParseError::EmptyId);
+    assert_eq!(reason(try_parse_username("<@!12a45>")), ParseError::NonNumericId);
+    assert_eq!(reason(try_parse_username("<@+12345>")), ParseError::NonNumericId);
+    assert_eq!(reason(try_parse_username("<@18446744073709551616>")), ParseError::IdOverflow);
+    assert_eq!(reason(try_parse_username("<@0>")), ParseError::ZeroId);
+    assert!(parse_username("<@12345x").is_none());
+}
+
+#[test]
+fn detailed_parse_errors() {
+    assert_eq!(try_parse_role("<@&12345>").unwrap(), 12_345);
+    assert_eq!(reason(try_parse_role("<#12345>")), ParseError::WrongPrefix);
+    assert_eq!(reason(try_parse_role("<@&12345")), ParseError::MissingClosingBracket);
+    assert_eq!(reason(try_parse_role("<@&abc>")), ParseError::NonNumericId);
+
+    assert_eq!(try_parse_channel("<#12345>").unwrap(), 12_345);
+    assert_eq!(reason(try_parse_channel("<#>")), ParseError::EmptyId);
+    assert_eq!(reason(try_parse_channel("<#99999999999999999999>")), ParseError::IdOverflow);
+
+    assert_eq!(try_parse_emoji("<a:name:12345>").unwrap().id, 12_345);
+    assert_eq!(reason(try_parse_emoji("<name:12345>")), ParseError::WrongPrefix);
+    assert_eq!(reason(try_parse_emoji("<:name:12345")), ParseError::MissingClosingBracket);
+    assert_eq!(reason(try_parse_emoji("<::12345>")), ParseError::EmptyName);
+    assert_eq!(reason(try_parse_emoji("<:name>")), ParseError::EmptyId);
+    assert_eq!(reason(try_parse_emoji("<:name:0>")), ParseError::ZeroId);
+
+    assert_eq!(reason(try_parse_emoji("<:a>b:12345>")), ParseError::InvalidName);
+    assert_eq!(reason(try_parse_emoji("<:smug face:12345>")), ParseError::InvalidName);
+    assert_eq!(reason(try_parse_emoji("<:smug-face:12345>")), ParseError::InvalidName);
+    assert_eq!(reason(try_parse_emoji("<:café:12345>")), ParseError::InvalidName);
+    assert_eq!(try_parse_emoji("<:smug_Face2:12345>").unwrap().name, "smug_Face2");
+    assert!(parse_emoji("<:a>b:12345>").is_none());
+
+    let long = format!("<:{}:12345>", "a".repeat(33));
+    assert_eq!(reason(try_parse_emoji(&long)), ParseError::NameTooLong);
+}
+
+#[test]
+fn message_link_parser() {
+    let expected = Some(MessageLink {
+        guild_id: Some(1),
+        channel_id: 2,
+        message_id: 3,
+    });
+
+    assert_eq!(parse_message_link("https://discord.com/channels/1/2/3"), expected);
+    assert_eq!(parse_message_link("http://discordapp.com/channels/1/2/3"), expected);
+    assert_eq!(parse_message_link("https://ptb.discord.com/channels/1/2/3"), expected);
+    assert_eq!(parse_message_link("canary.discord.com/channels/1/2/3"), expected);
+    assert_eq!(parse_message_link("https://discord.com/channels/@me/2/3").unwrap().guild_id, None);
+    assert_eq!(parse_message_link("https://discord.com/channels/1/2"), None);
+    assert_eq!(parse_message_link("https://discord.com/channels/1/2/3/4"), None);
+    assert_eq!(parse_message_link("https://discord.com/channels/1/2/x"), None);
+    assert_eq!(parse_message_link("https://discord.community/channels/1/2/3"), None);
+}
+
+#[test]
+fn invite_scanner() {
+    let text = "a discord.gg/abc, b <https://discord.com/invite/def?event=1> notdiscord.gg/ghi \
+                discord\u{200B}.gg/j\u{FEFF}kl [x](http://www.discordapp.com/invite/mno) \
+                discord(.)gg/pqr ~~discord.gg/s~~tu";
+    let found = find_invites(text).collect::<Vec<_>>();
+    let codes = found.iter().map(|invite| &invite.code[..]).collect::<Vec<_>>();
+
+    assert_eq!(codes, ["abc", "def", "jkl", "mno", "pqr", "stu"]);
+    assert_eq!(&text[found[0].span.clone()], "discord.gg/abc");
+    assert_eq!(&text[found[1].span.clone()], "https://discord.com/invite/def?event=1");
+    assert_eq!(found[1].event_id, Some(1));
+    assert_eq!(&text[found[2].span.clone()], "discord\u{200B}.gg/j\u{FEFF}kl");
+    assert_eq!(&text[found[3].span.clone()], "http://www.discordapp.com/invite/mno");
+    assert_eq!(&text[found[4].span.clone()], "discord(.)gg/pqr");
+    assert_eq!(&text[found[5].span.clone()], "discord.gg/s~~tu");
+    assert_eq!(find_invites("no invites here").count(), 0);
+
+    let text = "join ptb.discord.com/invite/abc or https://Canary.DiscordApp.com/invite/def";
+    let found = find_invites(text).collect::<Vec<_>>();
+
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].code, "abc");
+    assert_eq!(&text[found[0].span.clone()], "ptb.discord.com/invite/abc");
+    assert_eq!(found[1].code, "def");
+    assert_eq!(&text[found[1].span.clone()], "https://Canary.DiscordApp.com/invite/def");
+    assert_eq!(find_invites("evilptb.discord.com/invite/abc").count(), 0);
+    assert_eq!(find_invites("x.ptb.discord.com/invite/abc").count(), 0);
+
+    for text in &["Join now. discord.gg/abc", "Hi.\ndiscord.gg/abc", "Hi. **discord.gg/abc**"] {
+        let found = find_invites(text).collect::<Vec<_>>();
+
+        assert_eq!(found.len(), 1, "{:?}", text);
+        assert_eq!(found[0].code, "abc");
+        assert_eq!(&text[found[0].span.clone()], "discord.gg/abc");
+    }
+
+    assert_eq!(find_invites("evil.discord.gg/abc").count(), 0);
+}
+
+#[test]
+fn timestamp_parser() {
+    let parsed = parse_timestamp("<t:1618953630:F>").unwrap();
+    assert_eq!(parsed.unix, 1_618_953_630);
+    assert_eq!(parsed.style, Some(TimestampStyle::LongDateTime));
+
+    assert_eq!(parse_timestamp("<t:-100>").unwrap().unix, -100);
+    assert_eq!(parse_timestamp("<t:1618953630:>"), None);
+    assert_eq!(parse_timestamp("<t:1618953630:RR>"), None);
+    assert_eq!(parse_timestamp("<t:+1618953630>"), None);
+    assert_eq!(parse_timestamp("<t:>"), None);
+    assert_eq!(parse_timestamp("<d:1618953630>"), None);
+}
+
+#[test]
+fn timestamp_renderer() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let now = UNIX_EPOCH + Duration::from_secs(1_618_953_630);
+    let render = |unix, style| Timestamp { unix, style: Some(style) }.render(now);
+
+    assert_eq!(render(1_618_953_630, TimestampStyle::ShortTime), "9:20 PM");
+    assert_eq!(render(1_618_953_630, TimestampStyle::LongTime), "9:20:30 PM");
+    assert_eq!(render(1_618_953_630, TimestampStyle::ShortDate), "04/20/2021");
+    assert_eq!(render(1_618_953_630, TimestampStyle::LongDate), "April 20, 2021");
+    assert_eq!(render(1_618_953_630, TimestampStyle::ShortDateTime), "April 20, 2021 9:20 PM");
+    assert_eq!(render(1_618_953_630, TimestampStyle::LongDateTime), "Tuesday, April 20, 2021 9:20 PM");
+    assert_eq!(render(0, TimestampStyle::LongDateTime), "Thursday, January 1, 1970 12:00 AM");
+    assert_eq!(render(951_825_600, TimestampStyle::ShortDate), "02/29/2000");
+    assert_eq!(render(-1, TimestampStyle::LongTime), "11:59:59 PM");
+
+    assert_eq!(render(1_618_953_630 + 10, TimestampStyle::RelativeTime), "in a few seconds");
+    assert_eq!(render(1_618_953_630 - 60, TimestampStyle::RelativeTime), "a minute ago");
+    assert_eq!(render(1_618_953_630 - 5 * 60, TimestampStyle::RelativeTime), "5 minutes ago");
+    assert_eq!(render(1_618_953_630 + 3 * 3600, TimestampStyle::RelativeTime), "in 3 hours");
+    assert_eq!(render(1_618_953_630 - 86_400, TimestampStyle::RelativeTime), "a day ago");
+    assert_eq!(render(1_618_953_630 + 10 * 86_400, TimestampStyle::RelativeTime), "in 10 days");
+    assert_eq!(render(1_618_953_630 - 90 * 86_400, TimestampStyle::RelativeTime), "3 months ago");
+    assert_eq!(render(1_618_953_630 + 3 * 365 * 86_400, TimestampStyle::RelativeTime), "in 3 years");
+}
+
+#[test]
+fn command_parser() {
+    let command = parse_command("</ping:12345>").unwrap();
+    assert_eq!(command.name, "ping");
+    assert_eq!(command.subcommand_group, None);
+    assert_eq!(command.subcommand, None);
+    assert_eq!(command.id, 12_345);
+
+    let command = parse_command("</ban user:12345>").unwrap();
+    assert_eq!(command.subcommand_group, None);
+    assert_eq!(command.subcommand, Some("user"));
+
+    let command = parse_command("</permissions user get:12345>").unwrap();
+    assert_eq!(command.subcommand_group, Some("user"));
+    assert_eq!(command.subcommand, Some("get"));
+
+    assert_eq!(reason(try_parse_command("<ping:12345>")), ParseError::WrongPrefix);
+    assert_eq!(reason(try_parse_command("</ping:12345")), ParseError::MissingClosingBracket);
+    assert_eq!(reason(try_parse_command("</:12345>")), ParseError::EmptyName);
+    assert_eq!(reason(try_parse_command("</ban  user:12345>")), ParseError::EmptyName);
+    assert_eq!(reason(try_parse_command("</a b c d:12345>")), ParseError::TooManySubcommands);
+    assert_eq!(reason(try_parse_command("</ping>")), ParseError::EmptyId);
+    assert_eq!(reason(try_parse_command("</ping:abc>")), ParseError::NonNumericId);
+
+    let long = format!("</{}:12345>", "a".repeat(33));
+    assert_eq!(reason(try_parse_command(&long)), ParseError::NameTooLong);
+}
+
+#[test]
+fn duration_parser() {
+    use std::time::Duration;
+
+    let secs = |input| parse_duration(input).map(|duration| duration.as_secs()).ok();
+
+    assert_eq!(secs("90"), Some(90));
+    assert_eq!(secs("90s"), Some(90));
+    assert_eq!(secs("1h30m"), Some(5_400));
+    assert_eq!(secs("1h 30m"), Some(5_400));
+    assert_eq!(secs("2d"), Some(172_800));
+    assert_eq!(secs("1 week"), Some(604_800));
+    assert_eq!(secs("1 Year"), Some(31_536_000));
+    assert_eq!(secs("1 hour, 2 MINUTES and 3 secs"), Some(3_723));
+    assert_eq!(secs(" 5m "), Some(300));
+    assert_eq!(secs("0s"), Some(0));
+    assert_eq!(secs(""), None);
+    assert_eq!(secs("and"), None);
+    assert_eq!(secs("h"), None);
+    assert_eq!(secs("5 fortnights"), None);
+    assert_eq!(secs("-5m"), None);
+    assert_eq!(secs("1.5h"), None);
+    assert_eq!(secs("99999999999999999999s"), None);
+    assert_eq!(secs("584942417356y"), None);
+
+    assert_eq!(format_duration(Duration::from_secs(0)), "0s");
+    assert_eq!(format_duration(Duration::from_secs(31_536_000 + 60)), "1y1m");
+    assert_eq!(format_duration_long(Duration::from_millis(999)), "0 seconds");
+    assert_eq!(format_duration_long(Duration::from_secs(3_723)), "1 hour, 2 minutes and 3 seconds");
+    assert_eq!(format_duration_long(Duration::from_secs(1_209_601)), "2 weeks and 1 second");
+}
+
+#[test]
+fn colour_parser() {
+    let colour = |input: &str| input.parse::<Colour>().ok();
+
+    assert_eq!(colour("#7289DA"), Some(Colour::blurple()));
+    assert_eq!(colour("7289da"), Some(Colour::blurple()));
+    assert_eq!(colour("0x7289DA"), Some(Colour::blurple()));
+    assert_eq!(colour("0X7289DA"), Some(Colour::blurple()));
+    assert_eq!(colour(" #7289da "), Some(Colour::blurple()));
+    assert_eq!(colour("#1a2"), Some(Colour::new(0x11AA22)));
+    assert_eq!(colour("rgb(114,137,218)"), Some(Colour::blurple()));
+    assert_eq!(colour("RGB( 114 , 137 , 218 )"), Some(Colour::blurple()));
+    assert_eq!(colour("blurple"), Some(Colour::blurple()));
+    assert_eq!(colour("DARK_TEAL"), Some(Colour::dark_teal()));
+    assert_eq!(colour("dark teal"), Some(Colour::dark_teal()));
+    assert_eq!(colour("rohrkatze-blue"), Some(Colour::rohrkatze_blue()));
+
+    assert_eq!(colour(""), None);
+    assert_eq!(colour("1a2"), None);
+    assert_eq!(colour("#7289D"), None);
+    assert_eq!(colour("#7289DAA"), None);
+    assert_eq!(colour("#GGGGGG"), None);
+    assert_eq!(colour("#+12345"), None);
+    assert_eq!(colour("rgb(256, 0, 0)"), None);
+    assert_eq!(colour("rgb(1, 2)"), None);
+    assert_eq!(colour("rgb(1, 2, 3, 4)"), None);
+    assert_eq!(colour("rgb(1, 2, 3"), None);
+    assert_eq!(colour("blurple2"), None);
+    assert_eq!(colour("red❤"), None);
+    assert_eq!(colour("ab€"), None);
+    assert_eq!(colour("€€€€€€"), None);
+    assert_eq!(colour("rgb(1, 2, 3€)"), None);
+
+    match "nope".parse::<Colour>() {
+        Err(Error::Parse(ParseError::InvalidColour)) => {},
+        other => panic!("expected an invalid colour, got {:?}", other),
+    }
+}
@@ -0,0 +1,51 @@
+extern crate serenity_utils;
+
+use serenity_utils::*;
+
+#[test]
+fn shard_ranges_cover_all_shards() {
+    for &(clusters, shards) in &[(1, 1), (3, 10), (4, 16), (5, 3), (7, 100), (3, 2)] {
+        let mut next = 0;
+
+        for cluster in 0..clusters {
+            let range = shard_range(cluster, clusters, shards).unwrap();
+            assert_eq!(range.start, next);
+
+            for shard in range.clone() {
+                assert_eq!(cluster_id(shard, shards, clusters), Some(cluster));
+            }
+
+            next = range.end;
+        }
+
+        assert_eq!(next, shards);
+    }
+}
+
+#[test]
+fn shard_ranges_out_of_range() {
+    assert_eq!(shard_range(5, 3, 10), None);
+    assert_eq!(shard_range(3, 3, 10), None);
+    assert_eq!(shard_range(0, 0, 10), None);
+    assert_eq!(shard_range(4, 5, 3), Some(3..3));
+
+    assert_eq!(cluster_id(5, 2, 3), None);
+    assert_eq!(cluster_id(2, 2, 3), None);
+    assert_eq!(cluster_id(0, 10, 0), None);
+    assert_eq!(cluster_id(0, 0, 3), None);
+    assert_eq!(cluster_id(1, 2, 3), Some(1));
+}
+
+#[test]
+fn shard_validation() {
+    assert!(is_valid_shard(0, 1));
+    assert!(!is_valid_shard(1, 1));
+    assert!(!is_valid_shard(0, 0));
+}
+
+#[test]
+fn identify_buckets() {
+    assert_eq!(identify_bucket(0, 1), 0);
+    assert_eq!(identify_bucket(5, 1), 0);
+    assert_eq!(identify_bucket(17, 16), 1);
+}
@@ -0,0 +1,72 @@
+extern crate serenity_utils;
+
+use serenity_utils::*;
+use std::time::{Duration, UNIX_EPOCH};
+
+#[test]
+fn snowflake_fields() {
+    let snowflake = Snowflake(175_928_847_299_117_063);
+
+    assert_eq!(snowflake.timestamp(), 41_944_705_796);
+    assert_eq!(snowflake.created_at(), UNIX_EPOCH + Duration::from_millis(1_462_015_105_796));
+    assert_eq!(snowflake.worker_id(), 1);
+    assert_eq!(snowflake.process_id(), 0);
+    assert_eq!(snowflake.increment(), 7);
+}
+
+#[test]
+fn snowflake_from_timestamp() {
+    let time = UNIX_EPOCH + Duration::from_millis(1_462_015_105_796);
+
+    assert_eq!(Snowflake::from_timestamp(time), Snowflake(175_928_847_298_985_984));
+    assert_eq!(Snowflake::from_timestamp(UNIX_EPOCH), Snowflake(0));
+}
+
+#[test]
+fn snowflake_string_conversion() {
+    let snowflake = Snowflake(175_928_847_299_117_063);
+
+    assert_eq!(snowflake.to_string(), "175928847299117063");
+    assert_eq!("175928847299117063".parse::<Snowflake>().unwrap(), snowflake);
+    assert!("".parse::<Snowflake>().is_err());
+    assert!("-1".parse::<Snowflake>().is_err());
+    assert!("18446744073709551616".parse::<Snowflake>().is_err());
+}
+
+#[test]
+fn snowflake_timestamp_bounds() {
+    let millis = 1_462_015_105_796;
+    let time = UNIX_EPOCH + Duration::from_millis(millis);
+    let snowflake = Snowflake(175_928_847_299_117_063);
+
+    assert!(Snowflake::from_unix_millis(millis) <= snowflake);
+    assert!(Snowflake::max_from_unix_millis(millis) >= snowflake);
+    assert_eq!(Snowflake::from_unix_millis(millis), Snowflake::from_timestamp(time));
+    assert_eq!(Snowflake::max_from_unix_millis(millis), Snowflake::max_from_timestamp(time));
+    assert_eq!(Snowflake::max_from_unix_millis(millis).unix_millis(), millis);
+    assert_eq!(Snowflake::from_unix_millis(millis + 1).0, Snowflake::max_from_unix_millis(millis).0 + 1);
+    assert_eq!(Snowflake::from_unix_millis(0), Snowflake(0));
+}
+
+#[test]
+fn snowflake_between() {
+    let time = UNIX_EPOCH + Duration::from_millis(1_462_015_105_796);
+    let (after, before) = Snowflake::between(time, time);
+
+    assert_eq!(after, Snowflake(175_928_847_298_985_983));
+    assert_eq!(before, Snowflake(175_928_847_303_180_288));
+}
+
+#[test]
+fn snowflake_far_future() {
+    assert_eq!(Snowflake::from_unix_millis(u64::MAX), Snowflake(0xFFFF_FFFF_FFC0_0000));
+    assert_eq!(Snowflake::max_from_unix_millis(u64::MAX), Snowflake(u64::MAX));
+
+    // Ten thousand years is past the last Snowflake timestamp, but still
+    // within the range of `SystemTime` on every platform.
+    let time = UNIX_EPOCH + Duration::from_secs(10_000 * 365 * 24 * 60 * 60);
+
+    assert_eq!(Snowflake::from_timestamp(time), Snowflake(0xFFFF_FFFF_FFC0_0000));
+    assert_eq!(Snowflake::max_from_timestamp(time), Snowflake(u64::MAX));
+    assert_eq!(Snowflake::between(time, time).1, Snowflake(u64::MAX));
+}